@@ -36,6 +36,12 @@ int32_t molt_gil_acquire(void);
 int32_t molt_gil_release(void);
 int32_t molt_gil_is_held(void);
 
+typedef void (*MoltOutputSink)(void *ctx, const uint8_t *data, size_t len);
+void molt_set_output_hook(MoltOutputSink stdout_fn, MoltOutputSink stderr_fn,
+                          void *ctx);
+typedef void (*MoltUncaughtHandler)(void *ctx, MoltHandle exc_bits);
+void molt_set_uncaught_handler(MoltUncaughtHandler handler, void *ctx);
+
 void molt_handle_incref(MoltHandle handle);
 void molt_handle_decref(MoltHandle handle);
 MoltHandle molt_none(void);
@@ -225,6 +231,8 @@ static inline void *_molt_host_abi_symbol(const char *name) {
 #define molt_gil_acquire ((int32_t (*)(void))_molt_host_abi_symbol("molt_gil_acquire"))
 #define molt_gil_release ((int32_t (*)(void))_molt_host_abi_symbol("molt_gil_release"))
 #define molt_gil_is_held ((int32_t (*)(void))_molt_host_abi_symbol("molt_gil_is_held"))
+#define molt_set_output_hook ((void (*)(MoltOutputSink, MoltOutputSink, void *))_molt_host_abi_symbol("molt_set_output_hook"))
+#define molt_set_uncaught_handler ((void (*)(MoltUncaughtHandler, void *))_molt_host_abi_symbol("molt_set_uncaught_handler"))
 #define molt_handle_incref ((void (*)(MoltHandle))_molt_host_abi_symbol("molt_handle_incref"))
 #define molt_handle_decref ((void (*)(MoltHandle))_molt_host_abi_symbol("molt_handle_decref"))
 #define molt_none ((MoltHandle (*)(void))_molt_host_abi_symbol("molt_none"))
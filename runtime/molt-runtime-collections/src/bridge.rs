@@ -409,3 +409,22 @@ unsafe extern "C" {
 pub fn call_callable0(_py: &CoreGilToken, call_bits: u64) -> u64 {
     unsafe { __molt_collections_call_callable0(call_bits) }
 }
+
+// ---------------------------------------------------------------------------
+// Iteration helpers
+// ---------------------------------------------------------------------------
+
+unsafe extern "C" {
+    fn __molt_collections_molt_iter(bits: u64) -> u64;
+    fn __molt_collections_molt_iter_next(iter_bits: u64) -> u64;
+}
+
+/// Get an iterator for an object. Returns the iterator bits.
+pub fn molt_iter(_py: &CoreGilToken, bits: u64) -> u64 {
+    unsafe { __molt_collections_molt_iter(bits) }
+}
+
+/// Advance an iterator. Returns a `(value, done)` tuple's bits.
+pub fn molt_iter_next(_py: &CoreGilToken, iter_bits: u64) -> u64 {
+    unsafe { __molt_collections_molt_iter_next(iter_bits) }
+}
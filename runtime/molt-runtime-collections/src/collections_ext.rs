@@ -21,8 +21,9 @@ use crate::bridge::{
     attr_lookup_ptr_allow_missing, attr_name_bits_from_bytes, call_callable0, dec_ref_bits,
     dict_del_in_place, dict_get_in_place, dict_like_bits_from_ptr, dict_order_clone,
     dict_set_in_place, ensure_key_hashable, exception_pending, inc_ref_bits,
-    index_i64_with_overflow, is_truthy, obj_eq, object_type_id, raise_exception,
-    raise_key_error_with_key, seq_vec_ref, string_data, string_obj_to_owned, to_i64, type_name,
+    index_i64_with_overflow, is_truthy, molt_iter, molt_iter_next, obj_eq, object_type_id,
+    raise_exception, raise_key_error_with_key, seq_vec_ref, string_data, string_obj_to_owned,
+    to_i64, type_name,
 };
 
 use std::collections::{HashMap, VecDeque};
@@ -2305,28 +2306,94 @@ pub extern "C" fn molt_counter_new() -> u64 {
     })
 }
 
-/// Create a Counter by counting elements from a list/tuple iterable.
+/// Count every element of `iterable_bits` into `state`, walking lists and
+/// tuples directly and anything else through the iterator protocol. Returns
+/// `false` with an exception pending on failure.
+fn counter_count_iterable(
+    _py: &CoreGilToken,
+    state: &mut CounterState,
+    iterable_bits: u64,
+) -> bool {
+    if let Some(ptr) = obj_from_bits(iterable_bits).as_ptr() {
+        let type_id = unsafe { object_type_id(ptr) };
+        if type_id == TYPE_ID_LIST || type_id == TYPE_ID_TUPLE {
+            let elems = unsafe { seq_vec_ref(ptr) };
+            for &elem_bits in elems.iter() {
+                // CPython hashes each element as a dict key; an unhashable
+                // element raises TypeError (bare message for this path).
+                if !ensure_key_hashable(_py, elem_bits, 0) {
+                    return false;
+                }
+                state.add_count(_py, elem_bits, 1);
+            }
+            return true;
+        }
+    }
+    let iter_bits = molt_iter(_py, iterable_bits);
+    if obj_from_bits(iter_bits).is_none() {
+        if !exception_pending(_py) {
+            let msg = format!(
+                "'{}' object is not iterable",
+                type_name(_py, obj_from_bits(iterable_bits))
+            );
+            raise_exception::<u64>(_py, "TypeError", &msg);
+        }
+        return false;
+    }
+    let ok = loop {
+        let pair_bits = molt_iter_next(_py, iter_bits);
+        let pair = obj_from_bits(pair_bits)
+            .as_ptr()
+            .and_then(|pair_ptr| unsafe {
+                if object_type_id(pair_ptr) != TYPE_ID_TUPLE {
+                    return None;
+                }
+                let elems = seq_vec_ref(pair_ptr);
+                (elems.len() >= 2).then(|| (elems[0], elems[1]))
+            });
+        let Some((val_bits, done_bits)) = pair else {
+            // A malformed step with nothing pending would otherwise return
+            // None with no error set; surface it like next() does.
+            if !exception_pending(_py) {
+                let msg = format!(
+                    "'{}' object is not an iterator",
+                    type_name(_py, obj_from_bits(iter_bits))
+                );
+                raise_exception::<u64>(_py, "TypeError", &msg);
+            }
+            if obj_from_bits(pair_bits).as_ptr().is_some() {
+                dec_ref_bits(_py, pair_bits);
+            }
+            break false;
+        };
+        let step_ok = if is_truthy(_py, obj_from_bits(done_bits)) {
+            None
+        } else if ensure_key_hashable(_py, val_bits, 0) {
+            state.add_count(_py, val_bits, 1);
+            Some(true)
+        } else {
+            Some(false)
+        };
+        dec_ref_bits(_py, pair_bits);
+        match step_ok {
+            None => break true,
+            Some(false) => break false,
+            Some(true) => {}
+        }
+    };
+    dec_ref_bits(_py, iter_bits);
+    ok
+}
+
+/// Create a Counter by counting elements from any iterable.
 /// Each element becomes a key with count incremented by 1.
 #[unsafe(no_mangle)]
 pub extern "C" fn molt_counter_from_iterable(iterable_bits: u64) -> u64 {
     molt_runtime_core::with_core_gil!(_py, {
-        let obj = obj_from_bits(iterable_bits);
-        let Some(ptr) = obj.as_ptr() else {
-            return raise_exception::<_>(_py, "TypeError", "expected a list or tuple");
-        };
-        let type_id = unsafe { object_type_id(ptr) };
-        if type_id != TYPE_ID_LIST && type_id != TYPE_ID_TUPLE {
-            return raise_exception::<_>(_py, "TypeError", "expected a list or tuple");
-        }
-        let elems = unsafe { seq_vec_ref(ptr) };
         let mut state = CounterState::new();
-        for &elem_bits in elems.iter() {
-            // CPython hashes each element as a dict key; an unhashable element
-            // raises TypeError (bare message for the element-counting path).
-            if !ensure_key_hashable(_py, elem_bits, 0) {
-                return MoltObject::none().bits();
-            }
-            state.add_count(_py, elem_bits, 1);
+        if !counter_count_iterable(_py, &mut state, iterable_bits) {
+            state.clear(_py);
+            return MoltObject::none().bits();
         }
         let id = next_counter_handle();
         collections_state()
@@ -44,6 +44,8 @@ aborting_bridge_stub!(__molt_collections_exception_pending);
 aborting_bridge_stub!(__molt_collections_inc_ref_bits);
 aborting_bridge_stub!(__molt_collections_index_i64_with_overflow);
 aborting_bridge_stub!(__molt_collections_is_truthy);
+aborting_bridge_stub!(__molt_collections_molt_iter);
+aborting_bridge_stub!(__molt_collections_molt_iter_next);
 aborting_bridge_stub!(__molt_collections_obj_eq);
 aborting_bridge_stub!(__molt_collections_object_type_id);
 aborting_bridge_stub!(__molt_collections_raise_exception);
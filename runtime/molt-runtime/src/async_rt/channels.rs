@@ -1,13 +1,17 @@
-use crossbeam_channel::{Receiver, Sender, TryRecvError, TrySendError, bounded, unbounded};
+use crossbeam_channel::{
+    Receiver, SendTimeoutError, Sender, TryRecvError, TrySendError, bounded, unbounded,
+};
 #[cfg(molt_has_net_io)]
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::sync::Arc;
+#[cfg(molt_has_net_io)]
+use std::sync::OnceLock;
 #[cfg(molt_has_net_io)]
 use std::sync::atomic::AtomicU64;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering as AtomicOrdering};
-#[cfg(molt_has_net_io)]
-use std::sync::{Arc, OnceLock};
 use std::sync::{Condvar, Mutex};
+use std::time::Duration;
 
 use super::cancel_tokens;
 #[cfg(any(molt_has_net_io, target_arch = "wasm32"))]
@@ -20,17 +24,18 @@ use super::{current_token_id, token_id_from_bits};
 use crate::audit::{AuditArgs, audit_capability_decision};
 #[cfg(target_arch = "wasm32")]
 use crate::libc_compat as libc;
+use crate::object::native_handle::{native_handle_arc, native_handle_new};
 #[cfg(target_arch = "wasm32")]
 use crate::string_obj_to_owned;
 use crate::{
     GilReleaseGuard, MoltObject, PyToken, alloc_bytes, alloc_tuple, dec_ref_bits, inc_ref_bits,
     obj_from_bits, opaque_handle_bits, pending_bits_i64, ptr_from_bits, raise_exception,
-    release_ptr, runtime_state, to_i64, usize_from_bits,
+    release_ptr, runtime_state, to_f64, to_i64, usize_from_bits,
 };
 #[cfg(any(molt_has_net_io, target_arch = "wasm32"))]
 use crate::{
     IO_EVENT_ERROR, IO_EVENT_READ, IO_EVENT_WRITE, header_from_obj_ptr, monotonic_now_secs,
-    resolve_obj_ptr, to_f64,
+    resolve_obj_ptr,
 };
 #[cfg(molt_has_net_io)]
 use crate::{
@@ -73,6 +78,25 @@ pub struct MoltChannel {
     pub receiver: Receiver<i64>,
 }
 
+/// Channel of owned object references, exposed to Python as a refcounted
+/// native handle. Every queued value holds one reference: `send` takes a new
+/// one and `recv` hands it to the receiver, so values still queued when the
+/// last handle dies are released with the channel.
+pub struct MoltObjectChannel {
+    sender: Sender<u64>,
+    receiver: Receiver<u64>,
+}
+
+impl Drop for MoltObjectChannel {
+    fn drop(&mut self) {
+        crate::with_gil_entry_nopanic!(_py, {
+            while let Ok(bits) = self.receiver.try_recv() {
+                dec_ref_bits(_py, bits);
+            }
+        })
+    }
+}
+
 pub struct MoltStream {
     pub sender: Sender<Vec<u8>>,
     pub receiver: Receiver<Vec<u8>>,
@@ -368,6 +392,76 @@ fn chan_send_blocking_impl(_py: &PyToken<'_>, chan: &MoltChannel, val: i64) -> i
     }
 }
 
+/// `None`, infinite and out-of-range timeouts block until the value is
+/// queued; negative timeouts are rejected like `Lock.acquire`'s.
+fn chan_send_timeout_from_bits(
+    _py: &PyToken<'_>,
+    timeout_bits: u64,
+) -> Result<Option<Duration>, i64> {
+    let timeout_obj = obj_from_bits(timeout_bits);
+    if timeout_obj.is_none() {
+        return Ok(None);
+    }
+    let Some(timeout) = to_f64(timeout_obj) else {
+        return Err(raise_exception::<i64>(
+            _py,
+            "TypeError",
+            "timeout value must be a float",
+        ));
+    };
+    if timeout.is_nan() {
+        return Err(raise_exception::<i64>(
+            _py,
+            "ValueError",
+            "timeout value must not be NaN",
+        ));
+    }
+    if timeout < 0.0 {
+        return Err(raise_exception::<i64>(
+            _py,
+            "ValueError",
+            "timeout value must be a non-negative number",
+        ));
+    }
+    Ok(Duration::try_from_secs_f64(timeout).ok())
+}
+
+fn chan_send_timeout_impl(
+    _py: &PyToken<'_>,
+    chan: &MoltChannel,
+    val: i64,
+    timeout: Option<Duration>,
+) -> i64 {
+    let ok_bits = MoltObject::from_int(0).bits() as i64;
+    let bits = val as u64;
+    inc_ref_bits(_py, bits);
+    let sent = match chan.sender.try_send(val) {
+        Ok(_) => Ok(()),
+        Err(TrySendError::Full(_)) => {
+            let _release = GilReleaseGuard::new();
+            match timeout {
+                Some(timeout) => chan.sender.send_timeout(val, timeout),
+                None => chan
+                    .sender
+                    .send(val)
+                    .map_err(|err| SendTimeoutError::Disconnected(err.into_inner())),
+            }
+        }
+        Err(TrySendError::Disconnected(_)) => Err(SendTimeoutError::Disconnected(val)),
+    };
+    match sent {
+        Ok(()) => ok_bits,
+        Err(SendTimeoutError::Timeout(_)) => {
+            dec_ref_bits(_py, bits);
+            pending_bits_i64()
+        }
+        Err(SendTimeoutError::Disconnected(_)) => {
+            dec_ref_bits(_py, bits);
+            raise_exception::<i64>(_py, "RuntimeError", "channel disconnected")
+        }
+    }
+}
+
 #[cfg(molt_has_net_io)]
 fn chan_recv_blocking_impl(_py: &PyToken<'_>, chan: &MoltChannel) -> i64 {
     match chan.receiver.try_recv() {
@@ -480,6 +574,27 @@ pub unsafe extern "C" fn molt_chan_send_blocking(chan_handle: ChanHandle, val: i
     })
 }
 
+#[unsafe(no_mangle)]
+/// # Safety
+/// Caller must ensure `chan_handle` is a valid channel pointer.
+pub unsafe extern "C" fn molt_chan_send_timeout(
+    chan_handle: ChanHandle,
+    val: i64,
+    timeout_bits: u64,
+) -> i64 {
+    crate::with_gil_entry_nopanic!(_py, {
+        let timeout = match chan_send_timeout_from_bits(_py, timeout_bits) {
+            Ok(timeout) => timeout,
+            Err(err) => return err,
+        };
+        // SAFETY: caller guarantees `chan_handle` is valid for this call.
+        let chan_ptr = unsafe { chan_ptr_from_handle(chan_handle) };
+        // SAFETY: `chan_ptr` is expected to reference a live `MoltChannel`.
+        let chan = unsafe { &*(chan_ptr as *mut MoltChannel) };
+        chan_send_timeout_impl(_py, chan, val, timeout)
+    })
+}
+
 #[unsafe(no_mangle)]
 /// # Safety
 /// Caller must ensure `chan_handle` is a valid channel pointer.
@@ -534,6 +649,82 @@ pub unsafe extern "C" fn molt_chan_recv_blocking(chan_handle: ChanHandle) -> i64
     })
 }
 
+fn objchan_from_bits(bits: u64) -> Option<Arc<MoltObjectChannel>> {
+    native_handle_arc::<MoltObjectChannel>(bits)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn molt_objchan_new(capacity_bits: u64) -> u64 {
+    crate::with_gil_entry_nopanic!(_py, {
+        let capacity = match to_i64(obj_from_bits(capacity_bits)) {
+            Some(val) => val,
+            None => {
+                return raise_exception::<_>(
+                    _py,
+                    "TypeError",
+                    "channel capacity must be an integer",
+                );
+            }
+        };
+        if capacity < 0 {
+            return raise_exception::<_>(
+                _py,
+                "ValueError",
+                "channel capacity must be non-negative",
+            );
+        }
+        let capacity = capacity as usize;
+        let (sender, receiver) = if capacity == 0 {
+            unbounded()
+        } else {
+            bounded(capacity)
+        };
+        let bits = native_handle_new(_py, Arc::new(MoltObjectChannel { sender, receiver }));
+        if bits == 0 {
+            return raise_exception::<u64>(_py, "MemoryError", "out of memory");
+        }
+        bits
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn molt_objchan_send(chan_bits: u64, obj_bits: u64) -> u64 {
+    crate::with_gil_entry_nopanic!(_py, {
+        let Some(chan) = objchan_from_bits(chan_bits) else {
+            return raise_exception::<u64>(_py, "TypeError", "invalid channel handle");
+        };
+        inc_ref_bits(_py, obj_bits);
+        match chan.sender.try_send(obj_bits) {
+            Ok(()) => MoltObject::from_int(0).bits(),
+            Err(TrySendError::Full(_)) => {
+                dec_ref_bits(_py, obj_bits);
+                pending_bits_i64() as u64
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                dec_ref_bits(_py, obj_bits);
+                raise_exception::<u64>(_py, "RuntimeError", "channel disconnected")
+            }
+        }
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn molt_objchan_recv(chan_bits: u64) -> u64 {
+    crate::with_gil_entry_nopanic!(_py, {
+        let Some(chan) = objchan_from_bits(chan_bits) else {
+            return raise_exception::<u64>(_py, "TypeError", "invalid channel handle");
+        };
+        // The queued reference moves to the caller as-is.
+        match chan.receiver.try_recv() {
+            Ok(bits) => bits,
+            Err(TryRecvError::Empty) => pending_bits_i64() as u64,
+            Err(TryRecvError::Disconnected) => {
+                raise_exception::<u64>(_py, "RuntimeError", "channel disconnected")
+            }
+        }
+    })
+}
+
 fn bytes_channel(capacity: usize) -> (Sender<Vec<u8>>, Receiver<Vec<u8>>) {
     if capacity == 0 {
         unbounded()
@@ -3396,6 +3587,100 @@ pub unsafe extern "C" fn molt_ws_drop(ws_bits: u64) {
     })
 }
 
+#[cfg(test)]
+mod chan_tests {
+    use super::{
+        molt_chan_drop, molt_chan_new, molt_chan_recv, molt_chan_send_timeout, molt_objchan_new,
+        molt_objchan_recv, molt_objchan_send,
+    };
+    use crate::test_support::take_pending_exception;
+    use crate::{
+        MoltObject, alloc_list, dec_ref_bits, header_from_obj_ptr, pending_bits_i64, ptr_from_bits,
+    };
+    use std::sync::atomic::Ordering as AtomicOrdering;
+
+    fn ref_count(bits: u64) -> u32 {
+        unsafe {
+            (*header_from_obj_ptr(ptr_from_bits(bits)))
+                .ref_count
+                .load(AtomicOrdering::Relaxed)
+        }
+    }
+
+    #[test]
+    fn chan_send_timeout_returns_pending_while_full() {
+        let _guard = crate::TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        let chan = molt_chan_new(MoltObject::from_int(1).bits());
+        let timeout_bits = MoltObject::from_float(0.01).bits();
+        let first = MoltObject::from_int(1).bits() as i64;
+        let second = MoltObject::from_int(2).bits() as i64;
+        let ok = MoltObject::from_int(0).bits() as i64;
+        unsafe {
+            assert_eq!(molt_chan_send_timeout(chan, first, timeout_bits), ok);
+            assert_eq!(
+                molt_chan_send_timeout(chan, second, timeout_bits),
+                pending_bits_i64()
+            );
+            assert_eq!(molt_chan_recv(chan), first);
+            assert_eq!(molt_chan_send_timeout(chan, second, timeout_bits), ok);
+            assert_eq!(molt_chan_recv(chan), second);
+            molt_chan_drop(chan);
+        }
+    }
+
+    #[test]
+    fn chan_send_timeout_rejects_negative_and_blocks_on_huge_timeouts() {
+        let _guard = crate::TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        let chan = molt_chan_new(MoltObject::from_int(1).bits());
+        let value = MoltObject::from_int(1).bits() as i64;
+        let ok = MoltObject::from_int(0).bits() as i64;
+        unsafe {
+            molt_chan_send_timeout(chan, value, MoltObject::from_float(-1.0).bits());
+            assert_eq!(
+                take_pending_exception(),
+                Some((
+                    "ValueError".to_string(),
+                    "timeout value must be a non-negative number".to_string()
+                ))
+            );
+
+            // Too large for a Duration: treated as "block forever" instead of
+            // panicking. The channel has room, so this returns immediately.
+            let huge = MoltObject::from_float(1e300).bits();
+            assert_eq!(molt_chan_send_timeout(chan, value, huge), ok);
+            assert_eq!(molt_chan_recv(chan), value);
+            molt_chan_drop(chan);
+        }
+    }
+
+    #[test]
+    fn objchan_moves_references_and_releases_unreceived_values() {
+        let _guard = crate::TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        crate::with_gil_entry_nopanic!(_py, {
+            let chan = molt_objchan_new(MoltObject::from_int(0).bits());
+            let first = MoltObject::from_ptr(alloc_list(_py, &[])).bits();
+            let second = MoltObject::from_ptr(alloc_list(_py, &[])).bits();
+            let ok = MoltObject::from_int(0).bits();
+
+            assert_eq!(molt_objchan_send(chan, first), ok);
+            assert_eq!(molt_objchan_send(chan, second), ok);
+            assert_eq!(ref_count(first), 2);
+            assert_eq!(ref_count(second), 2);
+
+            let received = molt_objchan_recv(chan);
+            assert_eq!(received, first);
+            assert_eq!(ref_count(first), 2);
+            dec_ref_bits(_py, received);
+
+            dec_ref_bits(_py, chan);
+            assert_eq!(ref_count(second), 1);
+
+            dec_ref_bits(_py, first);
+            dec_ref_bits(_py, second);
+        });
+    }
+}
+
 #[cfg(test)]
 mod stream_tests {
     use super::{
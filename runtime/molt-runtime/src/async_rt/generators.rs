@@ -25,8 +25,8 @@ use crate::{
     generator_exception_stack_take, generator_raise_active, header_from_obj_ptr, inc_ref_bits,
     io_wait_poll_fn_addr, is_truthy, issubclass_bits, maybe_ptr_from_bits, missing_bits,
     molt_exception_clear, molt_exception_kind, molt_exception_last, molt_exception_set_last,
-    molt_is_callable, molt_raise, molt_str_from_obj, obj_from_bits, object_mark_has_ptrs,
-    object_type_id, pending_bits_i64, ptr_from_bits, raise_exception, register_task_token,
+    molt_is_callable, molt_raise, obj_from_bits, object_mark_has_ptrs, object_type_id,
+    pending_bits_i64, ptr_from_bits, raise_exception, raise_stop_iteration, register_task_token,
     resolve_task_ptr, runtime_state, seq_vec_ref, set_generator_raise, string_obj_to_owned,
     task_mark_done, task_waiting_on, to_i64, token_id_from_bits, type_name,
 };
@@ -236,27 +236,15 @@ fn generator_unpack_pair(_py: &PyToken<'_>, bits: u64) -> Option<(u64, bool)> {
     }
 }
 
-unsafe fn raise_stop_iteration_from_value(_py: &PyToken<'_>, value_bits: u64) -> u64 {
-    if obj_from_bits(value_bits).is_none() {
-        return raise_exception::<_>(_py, "StopIteration", "");
-    }
-    let msg_bits = molt_str_from_obj(value_bits);
-    let msg = string_obj_to_owned(obj_from_bits(msg_bits)).unwrap_or_default();
-    dec_ref_bits(_py, msg_bits);
-    raise_exception::<_>(_py, "StopIteration", &msg)
-}
-
 unsafe fn generator_method_result(_py: &PyToken<'_>, res_bits: u64) -> u64 {
-    unsafe {
-        if let Some((val_bits, done)) = generator_unpack_pair(_py, res_bits) {
-            if done {
-                return raise_stop_iteration_from_value(_py, val_bits);
-            }
-            inc_ref_bits(_py, val_bits);
-            return val_bits;
+    if let Some((val_bits, done)) = generator_unpack_pair(_py, res_bits) {
+        if done {
+            return raise_stop_iteration::<_>(_py, val_bits);
         }
-        res_bits
+        inc_ref_bits(_py, val_bits);
+        return val_bits;
     }
+    res_bits
 }
 
 #[unsafe(no_mangle)]
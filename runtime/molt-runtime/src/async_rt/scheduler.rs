@@ -21,12 +21,11 @@ use crate::{
     TYPE_ID_DICT, TYPE_ID_LIST, TYPE_ID_TUPLE, alloc_list, alloc_string, alloc_tuple,
     anext_default_poll_fn_addr, async_sleep_poll_fn_addr, asyncgen_poll_fn_addr, bits_from_ptr,
     call_callable0, call_poll_fn, class_name_for_error, code_filename_bits, code_name_bits,
-    context_stack_unwind, dec_ref_bits, dict_clear_in_place, dict_del_in_place, dict_get_in_place,
-    dict_set_in_place, exception_context_align_depth, exception_context_fallback_pop,
-    exception_context_fallback_push, exception_handler_active, exception_kind_bits,
-    exception_pending, exception_stack_baseline_get, exception_stack_baseline_set,
-    exception_stack_depth, exception_stack_set_depth, format_exception_with_traceback,
-    generator_raise_active, handle_system_exit, header_from_obj_ptr, inc_ref_bits,
+    dec_ref_bits, dict_clear_in_place, dict_del_in_place, dict_get_in_place, dict_set_in_place,
+    exception_context_align_depth, exception_context_fallback_pop, exception_context_fallback_push,
+    exception_handler_active, exception_kind_bits, exception_pending, exception_stack_baseline_get,
+    exception_stack_baseline_set, exception_stack_depth, exception_stack_set_depth,
+    generator_raise_active, handle_uncaught_exception, header_from_obj_ptr, inc_ref_bits,
     io_wait_poll_fn_addr, is_missing_bits, is_truthy, maybe_ptr_from_bits, missing_bits,
     molt_exception_last, molt_getattr_builtin, molt_set_add, molt_set_new, obj_from_bits,
     object_class_bits, object_type_id, pending_bits_i64, process_poll_fn_addr, profile_hit,
@@ -3792,15 +3791,7 @@ pub unsafe extern "C" fn molt_block_on(task_bits: u64) -> i64 {
             {
                 let exc_bits = molt_exception_last();
                 if let Some(exc_ptr) = maybe_ptr_from_bits(exc_bits) {
-                    let kind_bits = exception_kind_bits(exc_ptr);
-                    if string_obj_to_owned(obj_from_bits(kind_bits)).as_deref()
-                        == Some("SystemExit")
-                    {
-                        handle_system_exit(_py, exc_ptr);
-                    }
-                    context_stack_unwind(_py, exc_bits);
-                    eprintln!("{}", format_exception_with_traceback(_py, exc_ptr));
-                    std::process::exit(1);
+                    handle_uncaught_exception(_py, exc_ptr);
                 }
                 if !obj_from_bits(exc_bits).is_none() {
                     dec_ref_bits(_py, exc_bits);
@@ -3815,6 +3806,7 @@ pub unsafe extern "C" fn molt_block_on(task_bits: u64) -> i64 {
 mod tests {
     use super::*;
     use crate::{MoltObject, dec_ref_bits, header_from_obj_ptr, molt_future_new, ptr_from_bits};
+    use std::sync::atomic::AtomicUsize;
 
     fn ref_count(ptr: *mut u8) -> u32 {
         unsafe {
@@ -3850,4 +3842,40 @@ mod tests {
             dec_ref_bits(_py, MoltObject::from_ptr(awaited_ptr).bits());
         });
     }
+
+    static POLLED_RECURSION_LIMIT: AtomicUsize = AtomicUsize::new(0);
+
+    extern "C" fn record_recursion_limit_poll(_task_bits: u64) -> i64 {
+        let limit = crate::molt_getrecursionlimit();
+        let limit = MoltObject::from_bits(limit).as_int().unwrap_or(0);
+        POLLED_RECURSION_LIMIT.store(limit as usize, AtomicOrdering::SeqCst);
+        MoltObject::none().bits() as i64
+    }
+
+    #[test]
+    fn worker_thread_polls_under_setrecursionlimit_value() {
+        let _guard = crate::TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        let prev_limit = crate::state::recursion_limit_get();
+        POLLED_RECURSION_LIMIT.store(0, AtomicOrdering::SeqCst);
+        let _ = crate::molt_setrecursionlimit(MoltObject::from_int(77).bits());
+        let task_bits = molt_future_new(
+            record_recursion_limit_poll as extern "C" fn(u64) -> i64 as usize as u64,
+            0,
+        );
+        let task = MoltTask {
+            future_ptr: ptr_from_bits(task_bits),
+        };
+
+        // A fresh thread has never called setrecursionlimit itself, exactly
+        // like a scheduler worker picking the task off the injector.
+        thread::spawn(move || MoltScheduler::execute_task(task, &Injector::new()))
+            .join()
+            .unwrap();
+        assert_eq!(POLLED_RECURSION_LIMIT.load(AtomicOrdering::SeqCst), 77);
+
+        crate::state::recursion_limit_set(prev_limit);
+        crate::with_gil_entry_nopanic!(_py, {
+            dec_ref_bits(_py, task_bits);
+        });
+    }
 }
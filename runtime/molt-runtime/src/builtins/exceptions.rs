@@ -50,8 +50,8 @@ use exception_group::{
 
 mod exception_payload;
 pub(crate) use exception_group::{
-    molt_exceptiongroup_derive, molt_exceptiongroup_init, molt_exceptiongroup_split,
-    molt_exceptiongroup_subgroup,
+    molt_exception_group_new, molt_exceptiongroup_derive, molt_exceptiongroup_init,
+    molt_exceptiongroup_split, molt_exceptiongroup_subgroup,
 };
 pub(crate) use exception_payload::{
     alloc_exception_from_class_bits, format_exception, format_exception_message,
@@ -410,6 +410,33 @@ pub(crate) fn raise_exception<T: ExceptionSentinel>(
     T::exception_sentinel()
 }
 
+/// Raise `StopIteration(value)` carrying `value_bits` itself (not its `str`)
+/// as the single argument, so `.value` round-trips the generator's return
+/// value. A `None` value raises a bare `StopIteration()`.
+pub(crate) fn raise_stop_iteration<T: ExceptionSentinel>(_py: &PyToken<'_>, value_bits: u64) -> T {
+    if obj_from_bits(value_bits).is_none() {
+        return raise_exception::<T>(_py, "StopIteration", "");
+    }
+    let kind_ptr = alloc_string(_py, b"StopIteration");
+    if kind_ptr.is_null() {
+        return T::exception_sentinel();
+    }
+    let args_ptr = alloc_tuple(_py, &[value_bits]);
+    if args_ptr.is_null() {
+        unsafe { molt_dec_ref(kind_ptr) };
+        return T::exception_sentinel();
+    }
+    let kind_bits = MoltObject::from_ptr(kind_ptr).bits();
+    let args_bits = MoltObject::from_ptr(args_ptr).bits();
+    let exc_bits = molt_exception_new(kind_bits, args_bits);
+    dec_ref_bits(_py, kind_bits);
+    dec_ref_bits(_py, args_bits);
+    if let Some(exc_ptr) = obj_from_bits(exc_bits).as_ptr() {
+        record_exception_owned(_py, exc_ptr);
+    }
+    T::exception_sentinel()
+}
+
 pub(crate) fn raise_unicode_decode_error<T: ExceptionSentinel>(
     _py: &PyToken<'_>,
     encoding: &str,
@@ -598,7 +625,51 @@ pub(crate) fn handle_system_exit(_py: &PyToken<'_>, ptr: *mut u8) -> ! {
     }
     let message = format_obj(_py, code_obj);
     if !message.is_empty() {
-        eprintln!("{message}");
+        crate::state::runtime_stderr().write_line(&message);
+    }
+    std::process::exit(1);
+}
+
+/// `fn(ctx, exc_bits)`; `exc_bits` is borrowed for the duration of the call.
+pub type MoltUncaughtHandler = extern "C" fn(*mut u8, u64);
+
+static UNCAUGHT_HANDLER: crate::state::HostHook<Option<MoltUncaughtHandler>> =
+    crate::state::HostHook::new(None);
+
+/// Install (or, with a null function pointer, remove) the handler invoked for
+/// exceptions that escape every Python handler. Without one, the runtime
+/// prints the traceback and exits the process with status 1 (or honors
+/// `SystemExit`), which is the standalone behavior. With one, `with` blocks are
+/// unwound, the handler receives the exception, and control returns to the
+/// raising frame with the exception still pending; the host decides whether to
+/// unwind to its own boundary, log, or abort.
+///
+/// The handler runs on whichever thread the exception escaped on, with the
+/// GIL held, and receives `ctx` unchanged. It may call back into the runtime
+/// (to format or inspect the exception, say), but must take its own reference
+/// to `exc_bits` if it keeps the exception past the call.
+#[unsafe(no_mangle)]
+pub extern "C" fn molt_set_uncaught_handler(handler: Option<MoltUncaughtHandler>, ctx: *mut u8) {
+    UNCAUGHT_HANDLER.set(handler, ctx);
+}
+
+/// Terminal handling for an exception that escaped every handler.
+pub(crate) fn handle_uncaught_exception(_py: &PyToken<'_>, exc_ptr: *mut u8) {
+    let exc_bits = MoltObject::from_ptr(exc_ptr).bits();
+    if let (Some(handler), ctx) = UNCAUGHT_HANDLER.get() {
+        context_stack_unwind(_py, exc_bits);
+        handler(ctx, exc_bits);
+        return;
+    }
+    let kind_bits = unsafe { exception_kind_bits(exc_ptr) };
+    if string_obj_to_owned(obj_from_bits(kind_bits)).as_deref() == Some("SystemExit") {
+        handle_system_exit(_py, exc_ptr);
+    }
+    context_stack_unwind(_py, exc_bits);
+    let formatted = format_exception_with_traceback(_py, exc_ptr);
+    crate::state::runtime_stderr().write_line(&formatted);
+    if let Ok(path) = std::env::var("MOLT_EXCEPTION_LOG_PATH") {
+        let _ = std::fs::write(path, formatted.as_bytes());
     }
     std::process::exit(1);
 }
@@ -3342,9 +3413,10 @@ mod tests {
         alloc_exception, clear_exception, exception_context_set, exception_last_pending_bits,
         exception_last_public_bits, exception_method_bits, exception_pending, exception_stack_pop,
         exception_stack_push, exceptions_clear_runtime_state, format_exception,
-        format_exception_message, generator_exception_stack_drop, generator_exception_stack_store,
-        generator_exception_stack_take, molt_exception_new_builtin_one, record_exception,
-        task_exception_stack_drop, task_exception_stack_store, task_exception_stack_take,
+        format_exception_message, format_exception_with_traceback, generator_exception_stack_drop,
+        generator_exception_stack_store, generator_exception_stack_take,
+        molt_exception_new_builtin_one, record_exception, task_exception_stack_drop,
+        task_exception_stack_store, task_exception_stack_take,
     };
     use crate::builtins::containers::tuple_len;
     use crate::{dec_ref_bits, intern_static_name, obj_from_bits, runtime_state, seq_vec_ref};
@@ -3539,4 +3611,143 @@ mod tests {
             dec_ref_bits(_py, exc_bits);
         });
     }
+
+    static UNCAUGHT_SEEN: std::sync::Mutex<Vec<(usize, u64)>> = std::sync::Mutex::new(Vec::new());
+
+    extern "C" fn record_uncaught(ctx: *mut u8, exc_bits: u64) {
+        UNCAUGHT_SEEN.lock().unwrap().push((ctx as usize, exc_bits));
+    }
+
+    #[test]
+    fn uncaught_handler_receives_exception_instead_of_exiting() {
+        let _guard = crate::TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        UNCAUGHT_SEEN.lock().unwrap().clear();
+        crate::with_gil_entry_nopanic!(_py, {
+            super::molt_set_uncaught_handler(Some(record_uncaught), 7usize as *mut u8);
+            let value_error = alloc_exception(_py, "ValueError", "boom");
+            let system_exit = alloc_exception(_py, "SystemExit", "3");
+            super::handle_uncaught_exception(_py, value_error);
+            super::handle_uncaught_exception(_py, system_exit);
+            super::molt_set_uncaught_handler(None, std::ptr::null_mut());
+
+            let seen = std::mem::take(&mut *UNCAUGHT_SEEN.lock().unwrap());
+            assert_eq!(
+                seen,
+                vec![
+                    (7, MoltObject::from_ptr(value_error).bits()),
+                    (7, MoltObject::from_ptr(system_exit).bits()),
+                ]
+            );
+            dec_ref_bits(_py, MoltObject::from_ptr(value_error).bits());
+            dec_ref_bits(_py, MoltObject::from_ptr(system_exit).bits());
+        });
+    }
+
+    #[test]
+    fn exception_group_new_promotes_to_base_group_for_non_exception_members() {
+        let _guard = crate::TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        crate::with_gil_entry_nopanic!(_py, {
+            let builtins = crate::builtin_classes(_py);
+            let value_bits =
+                MoltObject::from_ptr(alloc_exception(_py, "ValueError", "bad value")).bits();
+            let interrupt_bits =
+                MoltObject::from_ptr(alloc_exception(_py, "KeyboardInterrupt", "")).bits();
+            let msg_bits = MoltObject::from_ptr(crate::alloc_string(_py, b"tasks failed")).bits();
+
+            let only_exceptions = crate::alloc_list(_py, &[value_bits]);
+            let only_exceptions_bits = MoltObject::from_ptr(only_exceptions).bits();
+            let group_bits =
+                super::exception_group::molt_exception_group_new(msg_bits, only_exceptions_bits);
+            let group_ptr = obj_from_bits(group_bits).as_ptr().expect("exception group");
+            assert_eq!(
+                unsafe { super::exception_class_bits(group_ptr) },
+                builtins.exception_group
+            );
+
+            let mixed = crate::alloc_list(_py, &[value_bits, interrupt_bits]);
+            let mixed_bits = MoltObject::from_ptr(mixed).bits();
+            let base_group_bits =
+                super::exception_group::molt_exception_group_new(msg_bits, mixed_bits);
+            let base_group_ptr = obj_from_bits(base_group_bits)
+                .as_ptr()
+                .expect("base exception group");
+            assert_eq!(
+                unsafe { super::exception_class_bits(base_group_ptr) },
+                builtins.base_exception_group
+            );
+
+            for bits in [
+                base_group_bits,
+                mixed_bits,
+                group_bits,
+                only_exceptions_bits,
+                msg_bits,
+                interrupt_bits,
+                value_bits,
+            ] {
+                dec_ref_bits(_py, bits);
+            }
+        });
+    }
+
+    #[test]
+    fn exception_group_new_rejects_non_str_message() {
+        let _guard = crate::TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = crate::molt_exception_clear();
+        crate::with_gil_entry_nopanic!(_py, {
+            let value_bits =
+                MoltObject::from_ptr(alloc_exception(_py, "ValueError", "bad value")).bits();
+            let list_bits = MoltObject::from_ptr(crate::alloc_list(_py, &[value_bits])).bits();
+
+            let out = super::exception_group::molt_exception_group_new(
+                MoltObject::from_int(5).bits(),
+                list_bits,
+            );
+            assert!(obj_from_bits(out).is_none());
+            assert_eq!(
+                crate::test_support::take_pending_exception(),
+                Some((
+                    "TypeError".to_string(),
+                    "BaseExceptionGroup.__new__() argument 1 must be str, not int".to_string()
+                ))
+            );
+
+            dec_ref_bits(_py, list_bits);
+            dec_ref_bits(_py, value_bits);
+        });
+    }
+
+    #[test]
+    fn exception_group_traceback_renders_each_sub_exception() {
+        let _guard = crate::TEST_MUTEX.lock().unwrap();
+        crate::with_gil_entry_nopanic!(_py, {
+            let first_bits =
+                MoltObject::from_ptr(alloc_exception(_py, "ValueError", "bad value")).bits();
+            let second_bits =
+                MoltObject::from_ptr(alloc_exception(_py, "TypeError", "bad type")).bits();
+            let list_bits =
+                MoltObject::from_ptr(crate::alloc_list(_py, &[first_bits, second_bits])).bits();
+            let msg_bits = MoltObject::from_ptr(crate::alloc_string(_py, b"tasks failed")).bits();
+
+            let group_bits = super::exception_group::molt_exception_group_new(msg_bits, list_bits);
+            let group_ptr = obj_from_bits(group_bits)
+                .as_ptr()
+                .expect("exception group object");
+            let rendered = format_exception_with_traceback(_py, group_ptr);
+            assert!(rendered.contains("  | ExceptionGroup: tasks failed (2 sub-exceptions)\n"));
+            assert!(rendered.contains(
+                "  +-+---------------- 1 ----------------\n    | ValueError: bad value\n"
+            ));
+            assert!(
+                rendered.contains(
+                    "    +---------------- 2 ----------------\n    | TypeError: bad type\n"
+                )
+            );
+            assert!(rendered.ends_with("    +------------------------------------"));
+
+            for bits in [group_bits, msg_bits, list_bits, second_bits, first_bits] {
+                dec_ref_bits(_py, bits);
+            }
+        });
+    }
 }
@@ -769,6 +769,38 @@ pub(super) fn alloc_exception_group_from_class_bits(
     }
 }
 
+/// Builds a group from a message and a sequence of exceptions, e.g. the
+/// failures collected from several joined tasks. Like
+/// `BaseExceptionGroup.__new__`, the result is an `ExceptionGroup` when every
+/// member is an `Exception` and a `BaseExceptionGroup` otherwise.
+#[unsafe(no_mangle)]
+pub extern "C" fn molt_exception_group_new(msg_bits: u64, excs_bits: u64) -> u64 {
+    crate::with_gil_entry_nopanic!(_py, {
+        let msg_obj = obj_from_bits(msg_bits);
+        let is_str = msg_obj
+            .as_ptr()
+            .is_some_and(|ptr| unsafe { object_type_id(ptr) } == TYPE_ID_STRING);
+        if !is_str {
+            let msg = format!(
+                "BaseExceptionGroup.__new__() argument 1 must be str, not {}",
+                type_name(_py, msg_obj)
+            );
+            return raise_exception::<u64>(_py, "TypeError", &msg);
+        }
+        let Some(collected) = exception_group_collect_exceptions(_py, excs_bits) else {
+            return MoltObject::none().bits();
+        };
+        let builtins = builtin_classes(_py);
+        let class_bits = if collected.all_exception {
+            builtins.exception_group
+        } else {
+            builtins.base_exception_group
+        };
+        exception_group_alloc(_py, class_bits, msg_bits, excs_bits, &collected.items, None)
+            .unwrap_or_else(|| MoltObject::none().bits())
+    })
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn molt_exceptiongroup_init(self_bits: u64, args_bits: u64) -> u64 {
     crate::with_gil_entry_nopanic!(_py, {
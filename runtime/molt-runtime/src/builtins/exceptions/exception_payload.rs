@@ -567,9 +567,44 @@ pub(crate) fn format_exception(_py: &PyToken<'_>, ptr: *mut u8) -> String {
     format!("{kind}{args_repr}")
 }
 
+/// CPython's `traceback` defaults for rendering nested exception groups.
+const EXCEPTION_GROUP_MAX_WIDTH: usize = 15;
+const EXCEPTION_GROUP_MAX_DEPTH: usize = 10;
+
 pub(crate) fn format_exception_with_traceback(_py: &PyToken<'_>, ptr: *mut u8) -> String {
+    let mut need_close = false;
+    let mut out = format_exception_chain(_py, ptr, 0, &mut need_close);
+    if out.ends_with('\n') {
+        out.pop();
+    }
+    out
+}
+
+/// Appends `text` line by line with the margin CPython uses inside an
+/// exception group at `group_depth` (no margin at the top level).
+fn exception_group_emit(out: &mut String, group_depth: usize, margin: char, text: &str) {
+    for line in text.split_inclusive('\n') {
+        if group_depth > 0 {
+            out.push_str(&"  ".repeat(group_depth));
+            out.push(margin);
+            out.push(' ');
+        }
+        out.push_str(line);
+    }
+    if !text.is_empty() && !text.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+fn format_exception_chain(
+    _py: &PyToken<'_>,
+    ptr: *mut u8,
+    group_depth: usize,
+    need_close: &mut bool,
+) -> String {
     // CPython displays chained exceptions recursively: context first,
     // then a separator, then the current exception.
+    let mut out = String::new();
     let suppress = unsafe { exception_suppress_bits(ptr) };
     let suppress_context = is_truthy(_py, obj_from_bits(suppress));
     if !suppress_context {
@@ -577,25 +612,132 @@ pub(crate) fn format_exception_with_traceback(_py: &PyToken<'_>, ptr: *mut u8) -
         let context_bits = unsafe { exception_context_bits(ptr) };
         if let Some(cause_ptr) = obj_from_bits(cause_bits).as_ptr() {
             if unsafe { object_type_id(cause_ptr) } == TYPE_ID_EXCEPTION {
-                let mut chain = format_exception_with_traceback(_py, cause_ptr);
-                chain.push_str(
-                    "\nThe above exception was the direct cause of the following exception:\n\n",
+                out.push_str(&format_exception_chain(
+                    _py,
+                    cause_ptr,
+                    group_depth,
+                    need_close,
+                ));
+                exception_group_emit(
+                    &mut out,
+                    group_depth,
+                    '|',
+                    "The above exception was the direct cause of the following exception:\n\n",
                 );
-                chain.push_str(&format_single_exception(_py, ptr));
-                return chain;
             }
         } else if let Some(ctx_ptr) = obj_from_bits(context_bits).as_ptr()
             && unsafe { object_type_id(ctx_ptr) } == TYPE_ID_EXCEPTION
         {
-            let mut chain = format_exception_with_traceback(_py, ctx_ptr);
-            chain.push_str(
-                "\nDuring handling of the above exception, another exception occurred:\n\n",
+            out.push_str(&format_exception_chain(
+                _py,
+                ctx_ptr,
+                group_depth,
+                need_close,
+            ));
+            exception_group_emit(
+                &mut out,
+                group_depth,
+                '|',
+                "During handling of the above exception, another exception occurred:\n\n",
             );
-            chain.push_str(&format_single_exception(_py, ptr));
-            return chain;
         }
     }
-    format_single_exception(_py, ptr)
+    if exception_is_group(_py, ptr) {
+        format_exception_group(_py, ptr, group_depth, need_close, &mut out);
+    } else if group_depth == 0 {
+        out.push_str(&format_single_exception(_py, ptr));
+        out.push('\n');
+    } else {
+        // Sub-exceptions only carry the frames they were raised through; the
+        // synthesized frame-stack header is reserved for the top level.
+        if let Some(trace) = format_traceback(_py, ptr) {
+            exception_group_emit(&mut out, group_depth, '|', &trace);
+        }
+        exception_group_emit(&mut out, group_depth, '|', &format_exception_only(_py, ptr));
+    }
+    out
+}
+
+fn format_exception_group(
+    _py: &PyToken<'_>,
+    ptr: *mut u8,
+    group_depth: usize,
+    need_close: &mut bool,
+    out: &mut String,
+) {
+    if group_depth > EXCEPTION_GROUP_MAX_DEPTH {
+        let msg = format!("... (max_group_depth is {EXCEPTION_GROUP_MAX_DEPTH})");
+        exception_group_emit(out, group_depth, '|', &msg);
+        return;
+    }
+    let is_toplevel = group_depth == 0;
+    let depth = group_depth.max(1);
+    if let Some(trace) = format_traceback(_py, ptr) {
+        let frames = trace
+            .strip_prefix("Traceback (most recent call last):\n")
+            .unwrap_or(&trace);
+        exception_group_emit(
+            out,
+            depth,
+            if is_toplevel { '+' } else { '|' },
+            "Exception Group Traceback (most recent call last):\n",
+        );
+        exception_group_emit(out, depth, '|', frames);
+    }
+    exception_group_emit(out, depth, '|', &format_exception_only(_py, ptr));
+    let children: Vec<u64> = exception_group_exceptions_bits(_py, ptr)
+        .and_then(|bits| obj_from_bits(bits).as_ptr())
+        .map(|seq_ptr| unsafe {
+            let type_id = object_type_id(seq_ptr);
+            if type_id == TYPE_ID_TUPLE || type_id == TYPE_ID_LIST {
+                seq_vec_ref(seq_ptr).to_vec()
+            } else {
+                Vec::new()
+            }
+        })
+        .unwrap_or_default();
+    let indent = "  ".repeat(depth);
+    let shown = children.len().min(EXCEPTION_GROUP_MAX_WIDTH + 1);
+    for (idx, &child_bits) in children.iter().take(shown).enumerate() {
+        let last = idx + 1 == shown;
+        if last {
+            // A nested group rendering the final child may emit the shared
+            // closing rule itself.
+            *need_close = true;
+        }
+        let truncated = idx >= EXCEPTION_GROUP_MAX_WIDTH;
+        let title = if truncated {
+            "...".to_string()
+        } else {
+            (idx + 1).to_string()
+        };
+        let lead = if idx == 0 { "+-" } else { "  " };
+        out.push_str(&format!(
+            "{indent}{lead}+---------------- {title} ----------------\n"
+        ));
+        if truncated {
+            let remaining = children.len() - EXCEPTION_GROUP_MAX_WIDTH;
+            let plural = if remaining > 1 { "s" } else { "" };
+            let msg = format!("and {remaining} more exception{plural}");
+            exception_group_emit(out, depth + 1, '|', &msg);
+        } else if let Some(child_ptr) = obj_from_bits(child_bits).as_ptr()
+            && unsafe { object_type_id(child_ptr) } == TYPE_ID_EXCEPTION
+        {
+            out.push_str(&format_exception_chain(
+                _py,
+                child_ptr,
+                depth + 1,
+                need_close,
+            ));
+        }
+        if last && *need_close {
+            let child_indent = "  ".repeat(depth + 1);
+            out.push_str(&format!(
+                "{child_indent}+------------------------------------\n"
+            ));
+            *need_close = false;
+        }
+    }
 }
 
 fn format_single_exception(_py: &PyToken<'_>, ptr: *mut u8) -> String {
@@ -629,14 +771,28 @@ fn format_single_exception(_py: &PyToken<'_>, ptr: *mut u8) -> String {
             }
         }
     }
+    out.push_str(&format_exception_only(_py, ptr));
+    out
+}
+
+fn exception_is_group(_py: &PyToken<'_>, ptr: *mut u8) -> bool {
+    let mut class_bits = unsafe { exception_class_bits(ptr) };
+    if obj_from_bits(class_bits).is_none() || class_bits == 0 {
+        class_bits = unsafe { exception_type_bits(_py, exception_kind_bits(ptr)) };
+    }
+    let base_group_bits = builtin_classes(_py).base_exception_group;
+    base_group_bits != 0 && issubclass_bits(class_bits, base_group_bits)
+}
+
+/// The final `Kind: message` line of a rendered traceback.
+fn format_exception_only(_py: &PyToken<'_>, ptr: *mut u8) -> String {
     let kind = exception_class_name(ptr);
     let message = format_exception_message(_py, ptr);
     if message.is_empty() {
-        out.push_str(&kind);
+        kind
     } else {
-        out.push_str(&format!("{kind}: {message}"));
+        format!("{kind}: {message}")
     }
-    out
 }
 
 pub(crate) fn format_exception_message(_py: &PyToken<'_>, ptr: *mut u8) -> String {
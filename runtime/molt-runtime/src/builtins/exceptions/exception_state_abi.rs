@@ -592,17 +592,7 @@ pub extern "C" fn molt_raise(exc_bits: u64) -> u64 {
             exception_context_set(_py, MoltObject::from_ptr(exc_ptr).bits());
         }
         if !exception_handler_active() && !generator_raise_active() && !task_raise_active() {
-            let kind_bits = unsafe { exception_kind_bits(exc_ptr) };
-            if string_obj_to_owned(obj_from_bits(kind_bits)).as_deref() == Some("SystemExit") {
-                handle_system_exit(_py, exc_ptr);
-            }
-            context_stack_unwind(_py, MoltObject::from_ptr(exc_ptr).bits());
-            let formatted = format_exception_with_traceback(_py, exc_ptr);
-            eprintln!("{}", formatted);
-            if let Ok(path) = std::env::var("MOLT_EXCEPTION_LOG_PATH") {
-                let _ = std::fs::write(path, formatted.as_bytes());
-            }
-            std::process::exit(1);
+            handle_uncaught_exception(_py, exc_ptr);
         }
         MoltObject::none().bits()
     })
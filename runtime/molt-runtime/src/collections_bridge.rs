@@ -402,6 +402,20 @@ pub extern "C" fn __molt_collections_call_callable0(call_bits: u64) -> u64 {
     })
 }
 
+// ---------------------------------------------------------------------------
+// Iteration helpers
+// ---------------------------------------------------------------------------
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __molt_collections_molt_iter(bits: u64) -> u64 {
+    crate::object::ops_iter::molt_iter(bits)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __molt_collections_molt_iter_next(iter_bits: u64) -> u64 {
+    crate::object::ops_iter::molt_iter_next(iter_bits)
+}
+
 #[cfg(all(test, feature = "stdlib_collections"))]
 mod tests {
     use crate::{TYPE_ID_FUNCTION, dec_ref_bits, obj_from_bits, object_type_id};
@@ -475,4 +489,33 @@ mod tests {
             );
         });
     }
+
+    #[test]
+    fn counter_from_iterable_walks_non_sequence_iterables() {
+        use crate::molt_runtime_collections::collections_ext::{
+            molt_counter_drop, molt_counter_from_iterable, molt_counter_getitem, molt_counter_len,
+        };
+        init_runtime();
+
+        crate::with_gil_entry_nopanic!(_py, {
+            let text_bits = MoltObject::from_ptr(crate::alloc_string(_py, b"abca")).bits();
+            let handle_bits = molt_counter_from_iterable(text_bits);
+            assert!(!obj_from_bits(handle_bits).is_none());
+            assert_eq!(
+                obj_from_bits(molt_counter_len(handle_bits)).as_int(),
+                Some(3)
+            );
+            let key_bits = MoltObject::from_ptr(crate::alloc_string(_py, b"a")).bits();
+            let count = molt_counter_getitem(handle_bits, key_bits);
+            assert_eq!(obj_from_bits(count).as_int(), Some(2));
+            dec_ref_bits(_py, key_bits);
+            let _ = molt_counter_drop(handle_bits);
+            dec_ref_bits(_py, text_bits);
+
+            let not_iterable = molt_counter_from_iterable(MoltObject::from_int(5).bits());
+            assert!(obj_from_bits(not_iterable).is_none());
+            assert!(crate::exception_pending(_py));
+            let _ = crate::molt_exception_clear();
+        });
+    }
 }
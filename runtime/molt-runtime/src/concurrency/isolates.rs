@@ -339,7 +339,8 @@ fn log_thread_exception(_py: &PyToken<'_>) {
         let exc_ptr = ptr_from_bits(exc_bits);
         if !exc_ptr.is_null() {
             let formatted = format_exception_with_traceback(_py, exc_ptr);
-            eprintln!("molt thread exception: {formatted}");
+            crate::state::runtime_stderr()
+                .write_line(&format!("molt thread exception: {formatted}"));
         }
     }
     molt_exception_clear();
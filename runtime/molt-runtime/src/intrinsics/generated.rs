@@ -2402,6 +2402,12 @@ pub(crate) const INTRINSICS: &[IntrinsicSpec] = &[
         arity: 2,
         defaults: &[],
     },
+    IntrinsicSpec {
+        name: "molt_chan_send_timeout",
+        symbol: "molt_chan_send_timeout",
+        arity: 3,
+        defaults: &[],
+    },
     IntrinsicSpec {
         name: "molt_chan_recv_blocking",
         symbol: "molt_chan_recv_blocking",
@@ -2414,6 +2420,54 @@ pub(crate) const INTRINSICS: &[IntrinsicSpec] = &[
         arity: 1,
         defaults: &[],
     },
+    IntrinsicSpec {
+        name: "molt_objchan_new",
+        symbol: "molt_objchan_new",
+        arity: 1,
+        defaults: &[],
+    },
+    IntrinsicSpec {
+        name: "molt_objchan_send",
+        symbol: "molt_objchan_send",
+        arity: 2,
+        defaults: &[],
+    },
+    IntrinsicSpec {
+        name: "molt_objchan_recv",
+        symbol: "molt_objchan_recv",
+        arity: 1,
+        defaults: &[],
+    },
+    IntrinsicSpec {
+        name: "molt_atomic_new",
+        symbol: "molt_atomic_new",
+        arity: 1,
+        defaults: &[],
+    },
+    IntrinsicSpec {
+        name: "molt_atomic_load",
+        symbol: "molt_atomic_load",
+        arity: 1,
+        defaults: &[],
+    },
+    IntrinsicSpec {
+        name: "molt_atomic_store",
+        symbol: "molt_atomic_store",
+        arity: 2,
+        defaults: &[],
+    },
+    IntrinsicSpec {
+        name: "molt_atomic_add",
+        symbol: "molt_atomic_add",
+        arity: 2,
+        defaults: &[],
+    },
+    IntrinsicSpec {
+        name: "molt_atomic_cas",
+        symbol: "molt_atomic_cas",
+        arity: 3,
+        defaults: &[],
+    },
     IntrinsicSpec {
         name: "molt_pending",
         symbol: "molt_pending",
@@ -7366,6 +7420,12 @@ pub(crate) const INTRINSICS: &[IntrinsicSpec] = &[
         arity: 0,
         defaults: &[],
     },
+    IntrinsicSpec {
+        name: "molt_exception_group_new",
+        symbol: "molt_exception_group_new",
+        arity: 2,
+        defaults: &[],
+    },
     IntrinsicSpec {
         name: "molt_gc_collect",
         symbol: "molt_gc_collect",
@@ -7552,6 +7612,12 @@ pub(crate) const INTRINSICS: &[IntrinsicSpec] = &[
         arity: 1,
         defaults: &[],
     },
+    IntrinsicSpec {
+        name: "molt_iter_send",
+        symbol: "molt_iter_send",
+        arity: 2,
+        defaults: &[],
+    },
     IntrinsicSpec {
         name: "molt_ast_parse",
         symbol: "molt_ast_parse",
@@ -27,6 +27,10 @@ pub(super) fn resolve_symbol(symbol: &str) -> Option<u64> {
             "crate::molt_chan_send_blocking",
             crate::molt_chan_send_blocking as *const (),
         )),
+        "molt_chan_send_timeout" => Some(crate::builtins::functions::runtime_fn_addr(
+            "crate::molt_chan_send_timeout",
+            crate::molt_chan_send_timeout as *const (),
+        )),
         "molt_chan_recv_blocking" => Some(crate::builtins::functions::runtime_fn_addr(
             "crate::molt_chan_recv_blocking",
             crate::molt_chan_recv_blocking as *const (),
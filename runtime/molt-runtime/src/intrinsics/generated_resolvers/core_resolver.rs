@@ -197,6 +197,38 @@ pub(super) fn resolve_symbol(symbol: &str) -> Option<u64> {
             "crate::molt_block_on",
             crate::molt_block_on as *const (),
         )),
+        "molt_objchan_new" => Some(crate::builtins::functions::runtime_fn_addr(
+            "crate::molt_objchan_new",
+            crate::molt_objchan_new as *const (),
+        )),
+        "molt_objchan_send" => Some(crate::builtins::functions::runtime_fn_addr(
+            "crate::molt_objchan_send",
+            crate::molt_objchan_send as *const (),
+        )),
+        "molt_objchan_recv" => Some(crate::builtins::functions::runtime_fn_addr(
+            "crate::molt_objchan_recv",
+            crate::molt_objchan_recv as *const (),
+        )),
+        "molt_atomic_new" => Some(crate::builtins::functions::runtime_fn_addr(
+            "crate::molt_atomic_new",
+            crate::molt_atomic_new as *const (),
+        )),
+        "molt_atomic_load" => Some(crate::builtins::functions::runtime_fn_addr(
+            "crate::molt_atomic_load",
+            crate::molt_atomic_load as *const (),
+        )),
+        "molt_atomic_store" => Some(crate::builtins::functions::runtime_fn_addr(
+            "crate::molt_atomic_store",
+            crate::molt_atomic_store as *const (),
+        )),
+        "molt_atomic_add" => Some(crate::builtins::functions::runtime_fn_addr(
+            "crate::molt_atomic_add",
+            crate::molt_atomic_add as *const (),
+        )),
+        "molt_atomic_cas" => Some(crate::builtins::functions::runtime_fn_addr(
+            "crate::molt_atomic_cas",
+            crate::molt_atomic_cas as *const (),
+        )),
         "molt_pending" => Some(crate::builtins::functions::runtime_fn_addr(
             "crate::molt_pending",
             crate::molt_pending as *const (),
@@ -353,6 +385,10 @@ pub(super) fn resolve_symbol(symbol: &str) -> Option<u64> {
             "crate::molt_exception_clear",
             crate::molt_exception_clear as *const (),
         )),
+        "molt_exception_group_new" => Some(crate::builtins::functions::runtime_fn_addr(
+            "crate::molt_exception_group_new",
+            crate::molt_exception_group_new as *const (),
+        )),
         "molt_getargv" => Some(crate::builtins::functions::runtime_fn_addr(
             "crate::molt_getargv",
             crate::molt_getargv as *const (),
@@ -373,6 +409,10 @@ pub(super) fn resolve_symbol(symbol: &str) -> Option<u64> {
             "crate::molt_gen_locals",
             crate::molt_gen_locals as *const (),
         )),
+        "molt_iter_send" => Some(crate::builtins::functions::runtime_fn_addr(
+            "crate::molt_iter_send",
+            crate::molt_iter_send as *const (),
+        )),
         "molt_function_init_metadata_packed" => Some(crate::builtins::functions::runtime_fn_addr(
             "crate::molt_function_init_metadata_packed",
             crate::molt_function_init_metadata_packed as *const (),
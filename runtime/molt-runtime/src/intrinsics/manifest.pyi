@@ -813,8 +813,17 @@ def molt_chan_recv(chan: Any) -> Any: ...
 def molt_chan_try_send(chan: Any, val: Any) -> int: ...
 def molt_chan_try_recv(chan: Any) -> Any: ...
 def molt_chan_send_blocking(chan: Any, val: Any) -> int: ...
+def molt_chan_send_timeout(chan: Any, val: Any, timeout: float | None) -> int: ...
 def molt_chan_recv_blocking(chan: Any) -> Any: ...
 def molt_chan_drop(chan: Any) -> None: ...
+def molt_objchan_new(maxsize: int = ...) -> Any: ...
+def molt_objchan_send(chan: Any, val: Any) -> int: ...
+def molt_objchan_recv(chan: Any) -> Any: ...
+def molt_atomic_new(init: int) -> Any: ...
+def molt_atomic_load(atomic: Any) -> int: ...
+def molt_atomic_store(atomic: Any, value: int) -> None: ...
+def molt_atomic_add(atomic: Any, delta: int) -> int: ...
+def molt_atomic_cas(atomic: Any, expected: int, new: int) -> bool: ...
 def molt_pending() -> Any: ...
 def molt_spawn(task: Any) -> None: ...
 def molt_async_sleep(_delay: float = ..., _result: Any | None = ...) -> Any: ...
@@ -2189,6 +2198,7 @@ def molt_exception_active() -> Any: ...
 def molt_exception_last() -> Any: ...
 def molt_exception_pending() -> int: ...
 def molt_exception_clear() -> None: ...
+def molt_exception_group_new(msg: str, excs: Any) -> Any: ...
 def molt_gc_collect(generation: int = ...) -> int: ...
 def molt_gc_enable() -> None: ...
 def molt_gc_disable() -> None: ...
@@ -2238,6 +2248,7 @@ def molt_asyncgen_hooks_get() -> Any: ...
 def molt_asyncgen_hooks_set(firstiter: Any, finalizer: Any) -> Any: ...
 def molt_asyncgen_locals(asyncgen: Any) -> Any: ...
 def molt_gen_locals(gen: Any) -> Any: ...
+def molt_iter_send(iterator: Any, value: Any) -> Any: ...
 def molt_ast_parse(
     source: str,
     filename: str,
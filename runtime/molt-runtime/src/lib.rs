@@ -160,6 +160,8 @@ pub mod refcount_verify;
 pub mod resource;
 mod socket_constants;
 mod state;
+#[cfg(test)]
+mod test_support;
 mod utils;
 pub mod vfs;
 mod wasm_abi_exports;
@@ -462,17 +464,17 @@ pub(crate) use crate::builtins::exceptions::{
     exceptions_clear_runtime_state, format_exception, format_exception_message,
     format_exception_with_traceback, generator_exception_stack_drop,
     generator_exception_stack_store, generator_exception_stack_take, generator_raise_active,
-    global_last_exception_bits_noinc, handle_system_exit, molt_exception_active,
-    molt_exception_clear, molt_exception_kind, molt_exception_last, molt_exception_pending,
-    molt_exception_set_last, molt_raise, raise_exception, raise_key_error_with_key,
-    raise_not_iterable, raise_unicode_decode_error, raise_unicode_encode_error,
-    raise_unsupported_inplace, record_exception, set_generator_raise, set_task_raise_active,
-    task_exception_baseline_drop, task_exception_baseline_store, task_exception_baseline_take,
-    task_exception_depth_drop, task_exception_depth_store, task_exception_depth_take,
-    task_exception_handler_stack_drop, task_exception_handler_stack_store,
-    task_exception_handler_stack_take, task_exception_stack_drop, task_exception_stack_store,
-    task_exception_stack_take, task_last_exception_contains_valid, task_last_exception_drop,
-    task_raise_active,
+    global_last_exception_bits_noinc, handle_uncaught_exception, molt_exception_active,
+    molt_exception_clear, molt_exception_group_new, molt_exception_kind, molt_exception_last,
+    molt_exception_pending, molt_exception_set_last, molt_raise, raise_exception,
+    raise_key_error_with_key, raise_not_iterable, raise_stop_iteration, raise_unicode_decode_error,
+    raise_unicode_encode_error, raise_unsupported_inplace, record_exception, set_generator_raise,
+    set_task_raise_active, task_exception_baseline_drop, task_exception_baseline_store,
+    task_exception_baseline_take, task_exception_depth_drop, task_exception_depth_store,
+    task_exception_depth_take, task_exception_handler_stack_drop,
+    task_exception_handler_stack_store, task_exception_handler_stack_take,
+    task_exception_stack_drop, task_exception_stack_store, task_exception_stack_take,
+    task_last_exception_contains_valid, task_last_exception_drop, task_raise_active,
 };
 pub(crate) use crate::builtins::exceptions::{raise_os_error, raise_os_error_errno};
 pub use crate::builtins::fcntl::*;
@@ -576,6 +578,9 @@ pub use crate::intrinsics::capabilities::*;
 pub(crate) use crate::object::accessors::{
     object_field_get_ptr_raw, object_field_set_ptr_raw, resolve_obj_ptr,
 };
+pub use crate::object::atomic::{
+    molt_atomic_add, molt_atomic_cas, molt_atomic_load, molt_atomic_new, molt_atomic_store,
+};
 pub use crate::object::buffer2d::*;
 pub use crate::object::builders::*;
 pub(crate) use crate::object::builders::{PtrDropGuard, alloc_dict_with_pairs};
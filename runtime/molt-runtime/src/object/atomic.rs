@@ -0,0 +1,208 @@
+//! Shared atomic integer for coordinating scheduler tasks.
+//!
+//! A `TYPE_ID_ATOMIC` object holds an `AtomicI64` inline in its payload, so
+//! every thread that holds a reference to the object operates on the same
+//! cell. All operations use `Ordering::SeqCst`: each load, store, add and
+//! compare-and-swap is part of a single total order observed identically by
+//! every worker, which is the model Python code expects when it treats the
+//! counter as shared state.
+
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use molt_obj_model::MoltObject;
+use num_traits::ToPrimitive;
+
+use crate::builtins::numbers::{bigint_ptr_from_bits, bigint_ref, index_i64_integral_bits};
+use crate::*;
+
+// Payload layout: [value: AtomicI64]
+
+#[inline]
+unsafe fn atomic_cell<'a>(ptr: *mut u8) -> &'a AtomicI64 {
+    unsafe { &*(ptr as *const AtomicI64) }
+}
+
+/// The atomic cell behind `bits`, or `TypeError` when `bits` is not an
+/// atomic object.
+fn atomic_from_bits<'a>(_py: &PyToken<'_>, bits: u64) -> Option<&'a AtomicI64> {
+    let obj = obj_from_bits(bits);
+    if let Some(ptr) = obj.as_ptr() {
+        unsafe {
+            if object_type_id(ptr) == TYPE_ID_ATOMIC {
+                return Some(atomic_cell(ptr));
+            }
+        }
+    }
+    let msg = format!("expected atomic, got {}", type_name(_py, obj));
+    raise_exception::<_>(_py, "TypeError", &msg)
+}
+
+/// Read an operand as an `i64`: ints, bools and `__index__` objects are
+/// accepted; ints outside the `i64` range raise `OverflowError`.
+fn atomic_operand(_py: &PyToken<'_>, bits: u64) -> Option<i64> {
+    if let Some(value) = index_i64_integral_bits(bits) {
+        return Some(value);
+    }
+    if let Some(ptr) = bigint_ptr_from_bits(bits) {
+        if let Some(value) = unsafe { bigint_ref(ptr) }.to_i64() {
+            return Some(value);
+        }
+        return raise_exception::<_>(
+            _py,
+            "OverflowError",
+            "Python int too large to convert to atomic int64",
+        );
+    }
+    let msg = format!(
+        "'{}' object cannot be interpreted as an integer",
+        type_name(_py, obj_from_bits(bits))
+    );
+    let value = index_i64_from_obj(_py, bits, &msg);
+    if exception_pending(_py) {
+        return None;
+    }
+    Some(value)
+}
+
+/// `atomic(init)`: a new counter holding `init`.
+#[unsafe(no_mangle)]
+pub extern "C" fn molt_atomic_new(init_bits: u64) -> u64 {
+    crate::with_gil_entry_nopanic!(_py, {
+        let Some(init) = atomic_operand(_py, init_bits) else {
+            return MoltObject::none().bits();
+        };
+        let total = std::mem::size_of::<MoltHeader>() + std::mem::size_of::<AtomicI64>();
+        let ptr = alloc_object(_py, total, TYPE_ID_ATOMIC);
+        if ptr.is_null() {
+            return MoltObject::none().bits();
+        }
+        unsafe {
+            std::ptr::write(ptr as *mut AtomicI64, AtomicI64::new(init));
+        }
+        MoltObject::from_ptr(ptr).bits()
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn molt_atomic_load(atomic_bits: u64) -> u64 {
+    crate::with_gil_entry_nopanic!(_py, {
+        let Some(cell) = atomic_from_bits(_py, atomic_bits) else {
+            return MoltObject::none().bits();
+        };
+        int_bits_from_i64(_py, cell.load(Ordering::SeqCst))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn molt_atomic_store(atomic_bits: u64, value_bits: u64) -> u64 {
+    crate::with_gil_entry_nopanic!(_py, {
+        let Some(cell) = atomic_from_bits(_py, atomic_bits) else {
+            return MoltObject::none().bits();
+        };
+        let Some(value) = atomic_operand(_py, value_bits) else {
+            return MoltObject::none().bits();
+        };
+        cell.store(value, Ordering::SeqCst);
+        MoltObject::none().bits()
+    })
+}
+
+/// Fetch-and-add returning the previous value. A sum outside the `i64`
+/// range raises `OverflowError` and leaves the counter unchanged rather than
+/// wrapping.
+#[unsafe(no_mangle)]
+pub extern "C" fn molt_atomic_add(atomic_bits: u64, delta_bits: u64) -> u64 {
+    crate::with_gil_entry_nopanic!(_py, {
+        let Some(cell) = atomic_from_bits(_py, atomic_bits) else {
+            return MoltObject::none().bits();
+        };
+        let Some(delta) = atomic_operand(_py, delta_bits) else {
+            return MoltObject::none().bits();
+        };
+        match cell.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |cur| {
+            cur.checked_add(delta)
+        }) {
+            Ok(prev) => int_bits_from_i64(_py, prev),
+            Err(_) => raise_exception::<_>(_py, "OverflowError", "atomic add overflows int64"),
+        }
+    })
+}
+
+/// Compare-and-swap: store `new` only if the counter still holds `expected`.
+/// Returns `True` when the swap happened.
+#[unsafe(no_mangle)]
+pub extern "C" fn molt_atomic_cas(atomic_bits: u64, expected_bits: u64, new_bits: u64) -> u64 {
+    crate::with_gil_entry_nopanic!(_py, {
+        let Some(cell) = atomic_from_bits(_py, atomic_bits) else {
+            return MoltObject::none().bits();
+        };
+        let Some(expected) = atomic_operand(_py, expected_bits) else {
+            return MoltObject::none().bits();
+        };
+        let Some(new) = atomic_operand(_py, new_bits) else {
+            return MoltObject::none().bits();
+        };
+        let swapped = cell
+            .compare_exchange(expected, new, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok();
+        MoltObject::from_bool(swapped).bits()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int_bits(value: i64) -> u64 {
+        MoltObject::from_int(value).bits()
+    }
+
+    #[test]
+    fn atomic_add_and_cas_are_shared_across_threads() {
+        let _guard = crate::TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        let atomic = molt_atomic_new(int_bits(5));
+        assert_eq!(molt_atomic_add(atomic, int_bits(3)), int_bits(5));
+        assert_eq!(molt_atomic_load(atomic), int_bits(8));
+
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                std::thread::spawn(move || {
+                    for _ in 0..250 {
+                        molt_atomic_add(atomic, int_bits(1));
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+        assert_eq!(molt_atomic_load(atomic), int_bits(1008));
+
+        let false_bits = MoltObject::from_bool(false).bits();
+        let true_bits = MoltObject::from_bool(true).bits();
+        assert_eq!(
+            molt_atomic_cas(atomic, int_bits(7), int_bits(0)),
+            false_bits
+        );
+        assert_eq!(
+            molt_atomic_cas(atomic, int_bits(1008), int_bits(-1)),
+            true_bits
+        );
+        let near_max =
+            crate::with_gil_entry_nopanic!(_py, { int_bits_from_i64(_py, i64::MAX - 1) });
+        molt_atomic_store(atomic, near_max);
+        crate::molt_dec_ref_obj(near_max);
+        let prev = molt_atomic_add(atomic, int_bits(1));
+        assert!(obj_from_bits(prev).as_ptr().is_some());
+        crate::molt_dec_ref_obj(prev);
+        molt_atomic_add(atomic, int_bits(1));
+        assert_eq!(
+            crate::test_support::take_pending_exception_kind().as_deref(),
+            Some("OverflowError")
+        );
+        let max_bits = crate::with_gil_entry_nopanic!(_py, { int_bits_from_i64(_py, i64::MAX) });
+        assert_eq!(molt_atomic_cas(atomic, max_bits, int_bits(0)), true_bits);
+        crate::molt_dec_ref_obj(max_bits);
+        crate::molt_dec_ref_obj(atomic);
+    }
+}
@@ -27,6 +27,7 @@ pub fn bump_type_version() -> u64 {
 }
 
 pub(crate) mod accessors;
+pub(crate) mod atomic;
 pub(crate) mod backing;
 pub(crate) mod buffer2d;
 pub(crate) mod builders;
@@ -600,6 +601,46 @@ unsafe fn free_shared_cold_idx_for_class(class_ptr: *mut u8) {
     }
 }
 
+/// Next lazily-assigned object identity. 0 is the "unassigned" marker in
+/// `MoltHeader::reserved`, so the counter skips it when it wraps.
+static NEXT_OBJECT_IDENTITY: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(1);
+
+/// Stable identity for a heap object, decoupled from its address.
+///
+/// Assigned from a monotonic counter on first request and cached in
+/// `MoltHeader::reserved`. Every allocation path zeroes the header, so a
+/// freed-then-reused allocation gets a fresh identity instead of inheriting
+/// its previous occupant's. Counter identities are odd (`n << 1 | 1`) and
+/// therefore never collide with the address fallback used for class objects,
+/// whose `reserved` word holds the shared cold-header cache (see
+/// `ensure_shared_cold_idx`). The counter is 32 bits wide and wraps after
+/// 2^32 assignments.
+///
+/// Safety: `ptr` must point to a live object.
+pub(crate) unsafe fn object_identity(ptr: *mut u8) -> u64 {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    unsafe {
+        if object_type_id(ptr) == TYPE_ID_TYPE {
+            return ptr as u64;
+        }
+        let header = header_from_obj_ptr(ptr);
+        // SAFETY: same in-place `AtomicU32` view as `ensure_shared_cold_idx`.
+        let reserved = &*(std::ptr::addr_of_mut!((*header).reserved) as *const AtomicU32);
+        let mut id = reserved.load(Ordering::Acquire);
+        if id == 0 {
+            let mut fresh = NEXT_OBJECT_IDENTITY.fetch_add(1, Ordering::Relaxed);
+            if fresh == 0 {
+                fresh = NEXT_OBJECT_IDENTITY.fetch_add(1, Ordering::Relaxed);
+            }
+            id = match reserved.compare_exchange(0, fresh, Ordering::AcqRel, Ordering::Acquire) {
+                Ok(_) => fresh,
+                Err(winner) => winner,
+            };
+        }
+        (u64::from(id) << 1) | 1
+    }
+}
+
 /// Derive the total allocation size from a header's `size_class`.
 /// For oversized objects (size_class == 0) the exact size is stored in
 /// the cold header's `extended_size`.
@@ -1833,9 +1874,11 @@ unsafe fn run_object_del_in_revival_window(py: &PyToken<'_>, ptr: *mut u8) {
         {
             let formatted =
                 crate::builtins::exceptions::format_exception_with_traceback(py, exc_ptr);
-            eprintln!("Exception ignored while calling deallocator:");
+            let mut stderr = crate::state::runtime_stderr();
+            stderr.write_line("Exception ignored while calling deallocator:");
             if !formatted.is_empty() {
-                eprint!("{formatted}");
+                let _ = stderr.write_all(formatted.as_bytes());
+                let _ = stderr.flush();
             }
         }
         crate::clear_exception(py);
@@ -2941,4 +2984,36 @@ mod tests {
             dec_ref_bits(_py, crate::MoltObject::from_ptr(allowed).bits());
         });
     }
+
+    #[test]
+    fn object_identity_is_lazy_stable_and_not_inherited_on_reuse() {
+        let _guard = crate::TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        crate::with_gil_entry_nopanic!(_py, {
+            let total = std::mem::size_of::<super::MoltHeader>() + 8;
+            let first = alloc_object(_py, total, TYPE_ID_OBJECT);
+            let second = alloc_object(_py, total, TYPE_ID_OBJECT);
+            unsafe {
+                assert_eq!((*super::header_from_obj_ptr(first)).reserved, 0);
+                let first_id = super::object_identity(first);
+                assert_eq!(super::object_identity(first), first_id);
+                let second_id = super::object_identity(second);
+                assert_ne!(first_id, second_id);
+                assert_eq!(first_id & 1, 1);
+                assert_eq!(
+                    crate::object::ops_hash::hash_object_identity(first),
+                    crate::object::ops_hash::hash_object_identity(first)
+                );
+
+                dec_ref_bits(_py, crate::MoltObject::from_ptr(first).bits());
+                let reused = alloc_object(_py, total, TYPE_ID_OBJECT);
+                assert_eq!((*super::header_from_obj_ptr(reused)).reserved, 0);
+                let reused_id = super::object_identity(reused);
+                assert_ne!(reused_id, first_id);
+                assert_ne!(reused_id, second_id);
+
+                dec_ref_bits(_py, crate::MoltObject::from_ptr(reused).bits());
+                dec_ref_bits(_py, crate::MoltObject::from_ptr(second).bits());
+            }
+        });
+    }
 }
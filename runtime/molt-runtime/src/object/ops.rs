@@ -18,7 +18,8 @@ pub(crate) use crate::object::ops_format::{
 // Re-export hash functions for backward compatibility with crate::object::ops::* paths
 pub(crate) use crate::object::ops_hash::{
     HashContext, HashSecret, ensure_hashable, fatal_hash_seed, hash_bits, hash_bits_signed,
-    hash_int, hash_pointer, hash_slice_bits, hash_string_bytes,
+    hash_int, hash_object_identity, hash_pointer, hash_slice_bits, hash_string_bytes,
+    reword_unhashable_key_error,
 };
 
 // Re-export encoding functions for backward compatibility with crate::object::ops::* paths
@@ -75,10 +76,10 @@ pub(crate) use dict_set_tables::{
     dict_del_in_place, dict_find_entry, dict_find_entry_fast, dict_find_entry_kv_in_place,
     dict_fromkeys_method, dict_get_in_place, dict_get_method, dict_inc_in_place,
     dict_inc_prehashed_string_key_in_place, dict_items_method, dict_keys_method,
-    dict_popitem_method, dict_rebuild, dict_set_in_place, dict_set_inline_int_in_place,
-    dict_setdefault_method, dict_table_capacity, dict_update_method, dict_update_set_via_store,
-    dict_values_method, set_add_in_place, set_del_in_place, set_find_entry, set_find_entry_fast,
-    set_replace_entries, set_table_capacity,
+    dict_popitem_in_place, dict_popitem_method, dict_rebuild, dict_set_in_place,
+    dict_set_inline_int_in_place, dict_setdefault_method, dict_table_capacity, dict_update_method,
+    dict_update_set_via_store, dict_values_method, set_add_in_place, set_del_in_place,
+    set_find_entry, set_find_entry_fast, set_replace_entries, set_table_capacity,
 };
 pub use dict_set_tables::{
     molt_string_split_sep_dict_inc, molt_string_split_ws_dict_inc, molt_taq_ingest_line,
@@ -2247,6 +2248,7 @@ pub(crate) fn type_name(_py: &PyToken<'_>, obj: MoltObject) -> Cow<'static, str>
                 TYPE_ID_MAP => Cow::Borrowed("map"),
                 TYPE_ID_FILTER => Cow::Borrowed("filter"),
                 TYPE_ID_NATIVE_HANDLE => Cow::Borrowed("native_handle"),
+                TYPE_ID_ATOMIC => Cow::Borrowed("atomic"),
                 TYPE_ID_CLASSMETHOD => Cow::Borrowed("classmethod"),
                 TYPE_ID_STATICMETHOD => Cow::Borrowed("staticmethod"),
                 TYPE_ID_PROPERTY => Cow::Borrowed("property"),
@@ -62,33 +62,43 @@ pub(crate) extern "C" fn dict_popitem_method(self_bits: u64) -> i64 {
             if object_type_id(ptr) != TYPE_ID_DICT {
                 return raise_exception::<_>(_py, "TypeError", "dict.popitem expects dict");
             }
-            let order = dict_order(ptr);
-            if order.len() < 2 {
-                return raise_exception::<_>(_py, "KeyError", "popitem(): dictionary is empty");
-            }
-            let key_bits = order[order.len() - 2];
-            let val_bits = order[order.len() - 1];
-            let item_ptr = alloc_tuple(_py, &[key_bits, val_bits]);
-            if item_ptr.is_null() {
-                return MoltObject::none().bits() as i64;
-            }
-            order.truncate(order.len() - 2);
-            let hashes = dict_hashes(ptr);
-            hashes.truncate(hashes.len().saturating_sub(1));
-            let entries = order.len() / 2;
-            let table = dict_table(ptr);
-            let capacity = dict_table_capacity(entries.max(1));
-            dict_rebuild(_py, order, hashes, table, capacity);
-            if order.is_empty() {
-                (*header_from_obj_ptr(ptr)).flags &= !crate::object::HEADER_FLAG_CONTAINS_REFS;
-            }
-            dec_ref_bits(_py, key_bits);
-            dec_ref_bits(_py, val_bits);
-            MoltObject::from_ptr(item_ptr).bits() as i64
+            dict_popitem_in_place(_py, ptr) as i64
         }
     })
 }
 
+/// Remove and return the newest `(key, value)` pair as a tuple, raising
+/// `KeyError` when the dict is empty. The entry's slot is tombstoned like
+/// `dict_del_in_place`, so the table is only rebuilt once tombstones cross
+/// the delete path's threshold.
+pub(crate) unsafe fn dict_popitem_in_place(_py: &PyToken<'_>, ptr: *mut u8) -> u64 {
+    unsafe {
+        let order = dict_order(ptr);
+        if order.len() < 2 {
+            return raise_exception::<_>(_py, "KeyError", "popitem(): dictionary is empty");
+        }
+        let key_idx = order.len() - 2;
+        let key_bits = order[key_idx];
+        let val_bits = order[key_idx + 1];
+        let item_ptr = alloc_tuple(_py, &[key_bits, val_bits]);
+        if item_ptr.is_null() {
+            return MoltObject::none().bits();
+        }
+        order.truncate(key_idx);
+        let hashes = dict_hashes(ptr);
+        hashes.truncate(key_idx / 2);
+        let table = dict_table(ptr);
+        let tombstones = dict_table_remove_entry(table, key_idx / 2);
+        dict_table_compact(_py, order, hashes, table, tombstones);
+        if order.is_empty() {
+            (*header_from_obj_ptr(ptr)).flags &= !crate::object::HEADER_FLAG_CONTAINS_REFS;
+        }
+        dec_ref_bits(_py, key_bits);
+        dec_ref_bits(_py, val_bits);
+        MoltObject::from_ptr(item_ptr).bits()
+    }
+}
+
 pub(crate) extern "C" fn dict_setdefault_method(
     self_bits: u64,
     key_bits: u64,
@@ -1361,6 +1371,50 @@ pub(crate) fn dict_rebuild(
     }
 }
 
+/// Tombstone the slot holding `entry_idx` after its pair was removed from
+/// `dict_order`, renumbering the entries that followed it. Returns the number
+/// of tombstones now in the table.
+fn dict_table_remove_entry(table: &mut [usize], entry_idx: usize) -> usize {
+    let removed_slot_val = entry_idx + 1;
+    let mut tombstones = 0usize;
+    for slot in table.iter_mut() {
+        if *slot == 0 {
+            continue;
+        }
+        if *slot == TABLE_TOMBSTONE {
+            tombstones = tombstones.saturating_add(1);
+            continue;
+        }
+        if *slot == removed_slot_val {
+            *slot = TABLE_TOMBSTONE;
+            tombstones = tombstones.saturating_add(1);
+            continue;
+        }
+        if *slot > removed_slot_val {
+            *slot -= 1;
+        }
+    }
+    tombstones
+}
+
+/// Rebuild the table once it is four times larger than the live entries need
+/// or more than a quarter of its slots are tombstones.
+fn dict_table_compact(
+    _py: &PyToken<'_>,
+    order: &[u64],
+    hashes: &[u64],
+    table: &mut Vec<usize>,
+    tombstones: usize,
+) {
+    let entries = order.len() / 2;
+    let desired_capacity = dict_table_capacity(entries.max(1));
+    if table.len() > desired_capacity.saturating_mul(4)
+        || tombstones.saturating_mul(4) > table.len()
+    {
+        dict_rebuild(_py, order, hashes, table, desired_capacity);
+    }
+}
+
 pub(crate) fn dict_find_entry_fast(
     _py: &PyToken<'_>,
     order: &[u64],
@@ -1408,12 +1462,19 @@ pub(crate) fn dict_find_entry(
     table: &[usize],
     key_bits: u64,
 ) -> Option<usize> {
+    let pending_before = exception_pending(_py);
+    // Hash before the empty check so an unhashable key raises even when the
+    // dict is empty, as in CPython. A failed hash always yields 0, so only
+    // that value needs the pending check.
+    let hash = hash_bits(_py, key_bits);
+    if hash == 0 && !pending_before && exception_pending(_py) {
+        reword_unhashable_key_error(_py, key_bits, HashContext::DictKey);
+        return None;
+    }
     if table.is_empty() {
         return None;
     }
-    let pending_before = exception_pending(_py);
     let mask = table.len() - 1;
-    let hash = hash_bits(_py, key_bits);
     let mut slot = (hash as usize) & mask;
     loop {
         let entry = table[slot];
@@ -2162,6 +2223,7 @@ pub(crate) unsafe fn dict_set_in_place(
             hash_bits(_py, key_bits)
         };
         if exception_pending(_py) {
+            reword_unhashable_key_error(_py, key_bits, HashContext::DictKey);
             return;
         }
         let order = dict_order(ptr);
@@ -2362,10 +2424,12 @@ pub(crate) unsafe fn dict_set_in_place_preserving_pending(
         let hash = hash_bits(_py, key_bits);
         if exception_pending(_py) {
             if !pending_before {
+                reword_unhashable_key_error(_py, key_bits, HashContext::DictKey);
                 return;
             }
             let after_exc_bits = exception_last_bits_noinc(_py).unwrap_or(0);
             if after_exc_bits != prev_exc_bits {
+                reword_unhashable_key_error(_py, key_bits, HashContext::DictKey);
                 return;
             }
         }
@@ -2458,6 +2522,7 @@ pub(crate) unsafe fn set_add_in_place(
         }
         let hash = hash_bits(_py, key_bits);
         if exception_pending(_py) {
+            reword_unhashable_key_error(_py, key_bits, ctx);
             return;
         }
         let order = set_order(ptr);
@@ -2715,32 +2780,8 @@ pub(crate) unsafe fn dict_del_in_place(_py: &PyToken<'_>, ptr: *mut u8, key_bits
         let val_idx = key_idx + 1;
         let removed: Vec<u64> = order.drain(key_idx..=val_idx).collect();
         hashes.remove(entry_idx);
-        let removed_slot_val = entry_idx + 1;
-        let mut tombstones = 0usize;
-        for slot in table.iter_mut() {
-            if *slot == 0 {
-                continue;
-            }
-            if *slot == TABLE_TOMBSTONE {
-                tombstones = tombstones.saturating_add(1);
-                continue;
-            }
-            if *slot == removed_slot_val {
-                *slot = TABLE_TOMBSTONE;
-                tombstones = tombstones.saturating_add(1);
-                continue;
-            }
-            if *slot > removed_slot_val {
-                *slot -= 1;
-            }
-        }
-        let entries = order.len() / 2;
-        let desired_capacity = dict_table_capacity(entries.max(1));
-        if table.len() > desired_capacity.saturating_mul(4)
-            || tombstones.saturating_mul(4) > table.len()
-        {
-            dict_rebuild(_py, order, hashes, table, desired_capacity);
-        }
+        let tombstones = dict_table_remove_entry(table, entry_idx);
+        dict_table_compact(_py, order, hashes, table, tombstones);
         if order.is_empty() {
             (*header_from_obj_ptr(ptr)).flags &= !crate::object::HEADER_FLAG_CONTAINS_REFS;
         }
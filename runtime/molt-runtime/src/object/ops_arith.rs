@@ -702,10 +702,26 @@ pub(crate) fn repeat_sequence(_py: &PyToken<'_>, ptr: *mut u8, count: i64) -> Op
             }
             TYPE_ID_STRING => {
                 let len = string_len(ptr);
+                if len == 0 || times == 1 {
+                    // Strings are immutable, so repeating "" or repeating once
+                    // yields the operand itself.
+                    let bits = MoltObject::from_ptr(ptr).bits();
+                    inc_ref_bits(_py, bits);
+                    return Some(bits);
+                }
                 let bytes = std::slice::from_raw_parts(string_bytes(ptr), len);
-                let total = match len.checked_mul(times) {
+                let total = match len
+                    .checked_mul(times)
+                    .filter(|&total| total <= isize::MAX as usize)
+                {
                     Some(total) => total,
-                    None => return raise_exception::<_>(_py, "MemoryError", "out of memory"),
+                    None => {
+                        return raise_exception::<_>(
+                            _py,
+                            "OverflowError",
+                            "repeated string is too long",
+                        );
+                    }
                 };
                 let out_ptr = alloc_bytes_like_with_len(_py, total, TYPE_ID_STRING);
                 if out_ptr.is_null() {
@@ -2738,7 +2738,7 @@ pub extern "C" fn molt_print_builtin(
             if obj_from_bits(resolved_file_bits).is_none() {
                 let encoding = "utf-8";
                 let errors = "surrogateescape";
-                let mut stdout = std::io::stdout();
+                let mut stdout = crate::state::runtime_stdout();
                 let mut wrote_newline = false;
                 let sep_bytes = if let Some(bits) = sep_bits_opt {
                     match encode_print_bytes(_py, bits, encoding, errors) {
@@ -9,8 +9,9 @@ use molt_obj_model::MoltObject;
 
 use super::ops::{
     dict_clear_in_place, dict_del_in_place, dict_find_entry, dict_get_in_place, dict_inc_in_place,
-    dict_inc_prehashed_string_key_in_place, dict_like_bits_from_ptr, dict_rebuild,
-    dict_set_in_place, dict_set_inline_int_in_place, dict_table_capacity, ensure_hashable,
+    dict_inc_prehashed_string_key_in_place, dict_like_bits_from_ptr, dict_popitem_in_place,
+    dict_rebuild, dict_set_in_place, dict_set_inline_int_in_place, dict_table_capacity,
+    ensure_hashable,
 };
 
 #[unsafe(no_mangle)]
@@ -761,29 +762,7 @@ pub extern "C" fn molt_dict_popitem(dict_bits: u64) -> u64 {
             if object_type_id(dict_ptr) != TYPE_ID_DICT {
                 return raise_exception::<_>(_py, "TypeError", "dict.popitem expects dict");
             }
-            let order = dict_order(dict_ptr);
-            if order.len() < 2 {
-                return raise_exception::<_>(_py, "KeyError", "popitem(): dictionary is empty");
-            }
-            let key_bits = order[order.len() - 2];
-            let val_bits = order[order.len() - 1];
-            let item_ptr = alloc_tuple(_py, &[key_bits, val_bits]);
-            if item_ptr.is_null() {
-                return MoltObject::none().bits();
-            }
-            order.truncate(order.len() - 2);
-            let hashes = dict_hashes(dict_ptr);
-            hashes.truncate(hashes.len().saturating_sub(1));
-            let entries = order.len() / 2;
-            let table = dict_table(dict_ptr);
-            let capacity = dict_table_capacity(entries.max(1));
-            dict_rebuild(_py, order, hashes, table, capacity);
-            if order.is_empty() {
-                (*header_from_obj_ptr(dict_ptr)).flags &= !crate::object::HEADER_FLAG_CONTAINS_REFS;
-            }
-            dec_ref_bits(_py, key_bits);
-            dec_ref_bits(_py, val_bits);
-            MoltObject::from_ptr(item_ptr).bits()
+            dict_popitem_in_place(_py, dict_ptr)
         }
     })
 }
@@ -1047,3 +1026,60 @@ pub extern "C" fn molt_dict_getitem_borrowed(dict_bits: u64, key_bits: u64) -> u
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::take_pending_exception_kind;
+
+    fn int_bits(value: i64) -> u64 {
+        MoltObject::from_int(value).bits()
+    }
+
+    fn dict_from_pairs(pairs: &[(i64, i64)]) -> u64 {
+        let flat: Vec<u64> = pairs
+            .iter()
+            .flat_map(|&(k, v)| [int_bits(k), int_bits(v)])
+            .collect();
+        crate::with_gil_entry_nopanic!(_py, {
+            MoltObject::from_ptr(alloc_dict_with_pairs(_py, &flat)).bits()
+        })
+    }
+
+    fn dict_keys(dict_bits: u64) -> Vec<i64> {
+        let ptr = obj_from_bits(dict_bits).as_ptr().expect("dict pointer");
+        unsafe { dict_order(ptr) }
+            .chunks_exact(2)
+            .map(|pair| obj_from_bits(pair[0]).as_int().expect("int key"))
+            .collect()
+    }
+
+    fn tuple_ints(tuple_bits: u64) -> Vec<i64> {
+        let ptr = obj_from_bits(tuple_bits).as_ptr().expect("tuple pointer");
+        let out = unsafe { seq_vec_ref(ptr) }
+            .iter()
+            .map(|&bits| obj_from_bits(bits).as_int().expect("int element"))
+            .collect();
+        crate::molt_dec_ref_obj(tuple_bits);
+        out
+    }
+
+    #[test]
+    fn popitem_drains_newest_first_and_keeps_lookups_valid() {
+        let _guard = crate::TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        let pairs: Vec<(i64, i64)> = (0..8).map(|k| (k, k * 10)).collect();
+        let dict = dict_from_pairs(&pairs);
+
+        for key in (0..8i64).rev() {
+            assert_eq!(tuple_ints(molt_dict_popitem(dict)), vec![key, key * 10]);
+            assert_eq!(dict_keys(dict), (0..key).collect::<Vec<_>>());
+            for k in 0..key {
+                let got = molt_dict_get(dict, int_bits(k), MoltObject::none().bits());
+                assert_eq!(got, int_bits(k * 10));
+            }
+        }
+        molt_dict_popitem(dict);
+        assert_eq!(take_pending_exception_kind().as_deref(), Some("KeyError"));
+        crate::molt_dec_ref_obj(dict);
+    }
+}
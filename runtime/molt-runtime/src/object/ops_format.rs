@@ -14,8 +14,9 @@ use super::ops_string::wtf8_from_bytes;
 /// Print a bare newline to stdout (used by the `print_newline` op).
 pub extern "C" fn molt_print_newline() {
     use std::io::Write;
-    let _ = std::io::stdout().write_all(b"\n");
-    let _ = std::io::stdout().flush();
+    let mut stdout = crate::state::runtime_stdout();
+    let _ = stdout.write_all(b"\n");
+    let _ = stdout.flush();
 }
 
 #[unsafe(no_mangle)]
@@ -41,12 +42,10 @@ pub extern "C" fn molt_warn_stderr(msg_bits: u64) {
     crate::with_gil_entry_nopanic!(_py, {
         let obj = obj_from_bits(msg_bits);
         if let Some(s) = string_obj_to_owned(obj) {
-            // Flush stdout first to ensure correct ordering when stdout and
-            // stderr are merged (e.g. `./binary 2>&1`).  CPython's warnings
-            // module does this implicitly through Python's I/O layer.
-            use std::io::Write;
-            let _ = std::io::stdout().flush();
-            eprintln!("{}", s);
+            // `write_line` flushes stdout first to ensure correct ordering when
+            // stdout and stderr are merged (e.g. `./binary 2>&1`).  CPython's
+            // warnings module does this implicitly through Python's I/O layer.
+            crate::state::runtime_stderr().write_line(&s);
         }
     })
 }
@@ -993,6 +993,18 @@ pub(crate) fn hash_pointer(ptr: u64) -> i64 {
     fix_hash(hash)
 }
 
+/// Default identity hash for heap objects: derived from the stable
+/// [`object_identity`] rather than the allocation address, so it survives
+/// allocator reuse and is reproducible across runs.
+pub(crate) fn hash_object_identity(ptr: *mut u8) -> i64 {
+    let id = unsafe { crate::object::object_identity(ptr) };
+    if id & 1 == 1 {
+        fix_hash((id >> 1) as i64)
+    } else {
+        hash_pointer(id)
+    }
+}
+
 fn hash_unhashable(_py: &PyToken<'_>, obj: MoltObject) -> i64 {
     let name = type_name(_py, obj);
     let msg = format!("unhashable type: '{name}'");
@@ -1126,7 +1138,7 @@ pub(crate) fn hash_bits_signed(_py: &PyToken<'_>, bits: u64) -> i64 {
             if type_id == TYPE_ID_DATACLASS {
                 let desc_ptr = dataclass_desc_ptr(ptr);
                 if desc_ptr.is_null() {
-                    return hash_pointer(ptr as u64);
+                    return hash_object_identity(ptr);
                 }
                 let desc = &*desc_ptr;
                 match desc.hash_mode {
@@ -1171,7 +1183,7 @@ pub(crate) fn hash_bits_signed(_py: &PyToken<'_>, bits: u64) -> i64 {
                                 "__hash__ returned non-int",
                             );
                         }
-                        return hash_pointer(ptr as u64);
+                        return hash_object_identity(ptr);
                     }
                     1 => {
                         let fields = dataclass_fields_ref(ptr);
@@ -1188,13 +1200,13 @@ pub(crate) fn hash_bits_signed(_py: &PyToken<'_>, bits: u64) -> i64 {
                             type_label,
                         );
                     }
-                    _ => return hash_pointer(ptr as u64),
+                    _ => return hash_object_identity(ptr),
                 }
             }
             if type_id == TYPE_ID_TYPE {
                 let metaclass_bits = type_of_bits(_py, obj.bits());
                 if metaclass_bits == builtin_classes(_py).type_obj {
-                    return hash_pointer(ptr as u64);
+                    return hash_object_identity(ptr);
                 }
                 let hash_name_bits =
                     intern_static_name(_py, &runtime_state(_py).interned.hash_name, b"__hash__");
@@ -1216,7 +1228,7 @@ pub(crate) fn hash_bits_signed(_py: &PyToken<'_>, bits: u64) -> i64 {
                 if meta_overrides_hash && let Some(hash) = hash_from_dunder(_py, obj, ptr) {
                     return hash;
                 }
-                return hash_pointer(ptr as u64);
+                return hash_object_identity(ptr);
             }
             if type_id == TYPE_ID_GENERIC_ALIAS {
                 return hash_generic_alias(_py, ptr);
@@ -1240,7 +1252,7 @@ pub(crate) fn hash_bits_signed(_py: &PyToken<'_>, bits: u64) -> i64 {
                 return hash;
             }
         }
-        return hash_pointer(ptr as u64);
+        return hash_object_identity(ptr);
     }
     hash_pointer(bits)
 }
@@ -1441,6 +1453,41 @@ pub(crate) fn unhashable_type_message(_py: &PyToken<'_>, name: &str, ctx: HashCo
     }
 }
 
+/// Reword the pending `TypeError` raised while hashing `key_bits` the way
+/// CPython 3.14's `dict_unhashable_type` does: the key's type goes in the
+/// prefix and the original message in parentheses, so a tuple key holding a
+/// list reports `cannot use 'tuple' as a dict key (unhashable type: 'list')`.
+/// Any other exception type, the bare context and pre-3.14 targets keep the
+/// original error. Only called once hashing has failed, so `ensure_hashable`
+/// never has to walk tuple keys up front.
+#[cold]
+#[inline(never)]
+pub(crate) fn reword_unhashable_key_error(_py: &PyToken<'_>, key_bits: u64, ctx: HashContext) {
+    let Some(word) = ctx.word() else {
+        return;
+    };
+    if !crate::object::ops_sys::runtime_target_at_least(_py, 3, 14) {
+        return;
+    }
+    let Some(exc_ptr) =
+        exception_last_bits_noinc(_py).and_then(|bits| obj_from_bits(bits).as_ptr())
+    else {
+        return;
+    };
+    let mut class_bits = unsafe { exception_class_bits(exc_ptr) };
+    if obj_from_bits(class_bits).is_none() || class_bits == 0 {
+        class_bits = unsafe { exception_type_bits(_py, exception_kind_bits(exc_ptr)) };
+    }
+    if class_bits != exception_type_bits_from_name(_py, "TypeError") {
+        return;
+    }
+    let detail = format_exception_message(_py, exc_ptr);
+    let key_name = type_name(_py, obj_from_bits(key_bits));
+    let msg = format!("cannot use '{key_name}' as a {word} ({detail})");
+    clear_exception(_py);
+    raise_exception::<()>(_py, "TypeError", &msg)
+}
+
 pub(crate) fn ensure_hashable(_py: &PyToken<'_>, key_bits: u64, ctx: HashContext) -> bool {
     let obj = obj_from_bits(key_bits);
     if let Some(ptr) = obj.as_ptr() {
@@ -1456,6 +1503,71 @@ pub(crate) fn ensure_hashable(_py: &PyToken<'_>, key_bits: u64, ctx: HashContext
     true
 }
 
+#[cfg(test)]
+mod unhashable_key_tests {
+    use crate::object::builders::{alloc_dict_with_pairs, alloc_list, alloc_tuple};
+    use crate::state::runtime_state::PythonVersionInfo;
+    use crate::test_support::take_pending_exception;
+    use crate::{MoltObject, dec_ref_bits, molt_dict_get, molt_dict_set, runtime_state};
+
+    fn set_target_minor(_py: &crate::PyToken<'_>, minor: i64) -> Option<PythonVersionInfo> {
+        let mut guard = runtime_state(_py).sys_version_info.lock().unwrap();
+        guard.replace(PythonVersionInfo {
+            major: 3,
+            minor,
+            micro: 0,
+            releaselevel: "final".to_string(),
+            serial: 0,
+        })
+    }
+
+    #[test]
+    fn tuple_key_with_list_member_names_key_and_member() {
+        let _guard = crate::TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = crate::molt_exception_clear();
+        crate::with_gil_entry_nopanic!(_py, {
+            let one = MoltObject::from_int(1).bits();
+            let list_bits = MoltObject::from_ptr(alloc_list(_py, &[one])).bits();
+            let inner_bits = MoltObject::from_ptr(alloc_tuple(_py, &[one, list_bits])).bits();
+            let outer_bits = MoltObject::from_ptr(alloc_tuple(_py, &[one, inner_bits])).bits();
+            let dict_bits = MoltObject::from_ptr(alloc_dict_with_pairs(_py, &[])).bits();
+            let none = MoltObject::none().bits();
+
+            let previous = set_target_minor(_py, 14);
+            for key_bits in [inner_bits, outer_bits] {
+                molt_dict_set(dict_bits, key_bits, one);
+                assert_eq!(
+                    take_pending_exception(),
+                    Some((
+                        "TypeError".to_string(),
+                        "cannot use 'tuple' as a dict key (unhashable type: 'list')".to_string()
+                    ))
+                );
+                molt_dict_get(dict_bits, key_bits, none);
+                assert_eq!(
+                    take_pending_exception().map(|(_, msg)| msg).as_deref(),
+                    Some("cannot use 'tuple' as a dict key (unhashable type: 'list')")
+                );
+            }
+
+            set_target_minor(_py, 12);
+            molt_dict_set(dict_bits, outer_bits, one);
+            assert_eq!(
+                take_pending_exception(),
+                Some((
+                    "TypeError".to_string(),
+                    "unhashable type: 'list'".to_string()
+                ))
+            );
+            *runtime_state(_py).sys_version_info.lock().unwrap() = previous;
+
+            for bits in [dict_bits, outer_bits, inner_bits, list_bits] {
+                dec_ref_bits(_py, bits);
+            }
+        });
+    }
+}
+
 #[cfg(test)]
 mod numeric_hash_tests {
     //! Pins the shared modular numeric hash against CPython 3.12 reference
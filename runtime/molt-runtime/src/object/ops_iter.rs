@@ -227,14 +227,34 @@ pub extern "C" fn molt_enumerate_builtin(iter_bits: u64, start_bits: u64) -> u64
 pub extern "C" fn molt_next_builtin(iter_bits: u64, default_bits: u64) -> u64 {
     crate::with_gil_entry_nopanic!(_py, {
         let missing = missing_bits(_py);
-        let pair_bits = molt_iter_next(iter_bits);
-        let pair_obj = obj_from_bits(pair_bits);
-        let Some(pair_ptr) = pair_obj.as_ptr() else {
-            // A non-tuple (None) result means either the underlying __next__
-            // (or an internal allocation) raised -- propagate that exception
-            // unchanged -- or the object is simply not an iterator. CPython's
-            // next() rejects the latter with a type-qualified message *before*
-            // consulting any default, so this covers next(x) and next(x, d).
+        let default = (default_bits != missing).then_some(default_bits);
+        next_with_default(_py, iter_bits, default)
+    })
+}
+
+/// Advance `iter_bits` one step, unwrapping the `(value, done)` pair from
+/// `molt_iter_next`. On exhaustion returns a new reference to `default` when
+/// given, and raises `StopIteration` otherwise.
+fn next_with_default(_py: &PyToken<'_>, iter_bits: u64, default: Option<u64>) -> u64 {
+    let pair_bits = molt_iter_next(iter_bits);
+    let pair_obj = obj_from_bits(pair_bits);
+    let Some(pair_ptr) = pair_obj.as_ptr() else {
+        // A non-tuple (None) result means either the underlying __next__
+        // (or an internal allocation) raised -- propagate that exception
+        // unchanged -- or the object is simply not an iterator. CPython's
+        // next() rejects the latter with a type-qualified message *before*
+        // consulting any default, so this covers next(x) and next(x, d).
+        if exception_pending(_py) {
+            return MoltObject::none().bits();
+        }
+        let msg = format!(
+            "'{}' object is not an iterator",
+            type_name(_py, obj_from_bits(iter_bits))
+        );
+        return raise_exception::<_>(_py, "TypeError", &msg);
+    };
+    unsafe {
+        if object_type_id(pair_ptr) != TYPE_ID_TUPLE {
             if exception_pending(_py) {
                 return MoltObject::none().bits();
             }
@@ -243,48 +263,30 @@ pub extern "C" fn molt_next_builtin(iter_bits: u64, default_bits: u64) -> u64 {
                 type_name(_py, obj_from_bits(iter_bits))
             );
             return raise_exception::<_>(_py, "TypeError", &msg);
-        };
-        unsafe {
-            if object_type_id(pair_ptr) != TYPE_ID_TUPLE {
-                if exception_pending(_py) {
-                    return MoltObject::none().bits();
-                }
-                let msg = format!(
-                    "'{}' object is not an iterator",
-                    type_name(_py, obj_from_bits(iter_bits))
-                );
-                return raise_exception::<_>(_py, "TypeError", &msg);
-            }
-            let elems = seq_vec_ref(pair_ptr);
-            if elems.len() < 2 {
-                if exception_pending(_py) {
-                    return MoltObject::none().bits();
-                }
-                let msg = format!(
-                    "'{}' object is not an iterator",
-                    type_name(_py, obj_from_bits(iter_bits))
-                );
-                return raise_exception::<_>(_py, "TypeError", &msg);
+        }
+        let elems = seq_vec_ref(pair_ptr);
+        if elems.len() < 2 {
+            if exception_pending(_py) {
+                return MoltObject::none().bits();
             }
-            let val_bits = elems[0];
-            let done_bits = elems[1];
-            if is_truthy(_py, obj_from_bits(done_bits)) {
-                if default_bits != missing {
-                    inc_ref_bits(_py, default_bits);
-                    return default_bits;
-                }
-                if obj_from_bits(val_bits).is_none() {
-                    return raise_exception::<_>(_py, "StopIteration", "");
-                }
-                let msg_bits = molt_str_from_obj(val_bits);
-                let msg = string_obj_to_owned(obj_from_bits(msg_bits)).unwrap_or_default();
-                dec_ref_bits(_py, msg_bits);
-                return raise_exception::<_>(_py, "StopIteration", &msg);
+            let msg = format!(
+                "'{}' object is not an iterator",
+                type_name(_py, obj_from_bits(iter_bits))
+            );
+            return raise_exception::<_>(_py, "TypeError", &msg);
+        }
+        let val_bits = elems[0];
+        let done_bits = elems[1];
+        if is_truthy(_py, obj_from_bits(done_bits)) {
+            if let Some(default_bits) = default {
+                inc_ref_bits(_py, default_bits);
+                return default_bits;
             }
-            inc_ref_bits(_py, val_bits);
-            val_bits
+            return raise_stop_iteration::<_>(_py, val_bits);
         }
-    })
+        inc_ref_bits(_py, val_bits);
+        val_bits
+    }
 }
 
 pub(crate) unsafe fn map_new_impl(_py: &PyToken<'_>, func_bits: u64, iterables: &[u64]) -> u64 {
@@ -1002,30 +1004,62 @@ unsafe fn iter_return_cached(
     }
 }
 
+/// Resume a generator through the iterator protocol, returning its
+/// `(value, done)` pair and marking it closed once exhausted.
+unsafe fn generator_iter_step(
+    _py: &PyToken<'_>,
+    gen_ptr: *mut u8,
+    gen_bits: u64,
+    send_bits: u64,
+) -> u64 {
+    unsafe {
+        let res_bits = molt_generator_send(gen_bits, send_bits);
+        if exception_pending(_py) {
+            return res_bits;
+        }
+        let res_obj = obj_from_bits(res_bits);
+        if let Some(res_ptr) = res_obj.as_ptr()
+            && object_type_id(res_ptr) == TYPE_ID_TUPLE
+        {
+            let elems = seq_vec_ref(res_ptr);
+            if elems.len() >= 2 {
+                let done = is_truthy(_py, obj_from_bits(elems[1]));
+                if done {
+                    let closed_bits = MoltObject::from_bool(true).bits();
+                    *(gen_ptr.add(GEN_CLOSED_OFFSET) as *mut u64) = closed_bits;
+                }
+            }
+        }
+        res_bits
+    }
+}
+
+/// `iterator.send(value)` through the uniform iterator interface: generators
+/// resume with `value` and return the same `(value, done)` pair as
+/// `molt_iter_next`; other iterators have no `send` and raise `AttributeError`.
+#[unsafe(no_mangle)]
+pub extern "C" fn molt_iter_send(iter_bits: u64, value_bits: u64) -> u64 {
+    crate::with_gil_entry_nopanic!(_py, {
+        if let Some(ptr) = maybe_ptr_from_bits(iter_bits)
+            && unsafe { object_type_id(ptr) } == TYPE_ID_GENERATOR
+        {
+            return unsafe { generator_iter_step(_py, ptr, iter_bits, value_bits) };
+        }
+        let msg = format!(
+            "'{}' object has no attribute 'send'",
+            type_name(_py, obj_from_bits(iter_bits))
+        );
+        raise_exception::<_>(_py, "AttributeError", &msg)
+    })
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn molt_iter_next(iter_bits: u64) -> u64 {
     crate::with_gil_entry_nopanic!(_py, {
         if let Some(ptr) = maybe_ptr_from_bits(iter_bits) {
             unsafe {
                 if object_type_id(ptr) == TYPE_ID_GENERATOR {
-                    let res_bits = molt_generator_send(iter_bits, MoltObject::none().bits());
-                    if exception_pending(_py) {
-                        return res_bits;
-                    }
-                    let res_obj = obj_from_bits(res_bits);
-                    if let Some(res_ptr) = res_obj.as_ptr()
-                        && object_type_id(res_ptr) == TYPE_ID_TUPLE
-                    {
-                        let elems = seq_vec_ref(res_ptr);
-                        if elems.len() >= 2 {
-                            let done = is_truthy(_py, obj_from_bits(elems[1]));
-                            if done {
-                                let closed_bits = MoltObject::from_bool(true).bits();
-                                *(ptr.add(GEN_CLOSED_OFFSET) as *mut u64) = closed_bits;
-                            }
-                        }
-                    }
-                    return res_bits;
+                    return generator_iter_step(_py, ptr, iter_bits, MoltObject::none().bits());
                 }
                 if object_type_id(ptr) == TYPE_ID_GLOB_ITER {
                     // Lazy glob iterator: advance the native streaming state by
@@ -2224,6 +2258,7 @@ pub extern "C" fn molt_anext(obj_bits: u64) -> u64 {
 mod tests {
     use super::cached_pair_return;
     use crate::object::HEADER_FLAG_CONTAINS_REFS;
+    use crate::test_support::take_pending_exception_kind;
     use crate::{MoltObject, alloc_string, dec_ref_bits, header_from_obj_ptr, seq_vec_ref};
 
     #[test]
@@ -2280,4 +2315,194 @@ mod tests {
             }
         });
     }
+
+    extern "C" fn echo_poll(task_bits: u64) -> i64 {
+        crate::with_gil_entry_nopanic!(_py, {
+            let task_ptr = crate::ptr_from_bits(task_bits);
+            let sent = unsafe { *(task_ptr.add(crate::GEN_SEND_OFFSET) as *const u64) };
+            let value = MoltObject::from_bits(sent).as_int().unwrap_or(-1);
+            let pair = crate::alloc_tuple(
+                _py,
+                &[
+                    MoltObject::from_int(value * 10).bits(),
+                    MoltObject::from_bool(value >= 3).bits(),
+                ],
+            );
+            MoltObject::from_ptr(pair).bits() as i64
+        })
+    }
+
+    fn pair_of(bits: u64) -> (i64, bool) {
+        let ptr = MoltObject::from_bits(bits).as_ptr().expect("pair tuple");
+        let elems = unsafe { seq_vec_ref(ptr) };
+        (
+            MoltObject::from_bits(elems[0]).as_int().expect("int value"),
+            MoltObject::from_bits(elems[1])
+                .as_bool()
+                .expect("bool done"),
+        )
+    }
+
+    #[test]
+    fn iter_send_resumes_generators_and_rejects_plain_iterators() {
+        let _guard = crate::TEST_MUTEX
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let _ = crate::molt_exception_clear();
+        crate::with_gil_entry_nopanic!(_py, {
+            let depth_before = crate::exception_stack_depth();
+            let gen_bits = crate::molt_generator_new(
+                echo_poll as extern "C" fn(u64) -> i64 as usize as u64,
+                crate::GEN_CONTROL_SIZE as u64,
+            );
+            let none = MoltObject::none().bits();
+
+            let first = super::molt_iter_send(gen_bits, none);
+            assert_eq!(pair_of(first), (-10, false));
+            dec_ref_bits(_py, first);
+            for sent in 1..3 {
+                let res = super::molt_iter_send(gen_bits, MoltObject::from_int(sent).bits());
+                assert!(!crate::exception_pending(_py));
+                assert_eq!(pair_of(res), (sent * 10, false));
+                dec_ref_bits(_py, res);
+                assert_eq!(crate::exception_stack_depth(), depth_before);
+            }
+            let last = super::molt_iter_send(gen_bits, MoltObject::from_int(3).bits());
+            assert_eq!(pair_of(last), (30, true));
+            dec_ref_bits(_py, last);
+            assert_eq!(crate::exception_stack_depth(), depth_before);
+
+            let list_ptr = crate::alloc_list(_py, &[MoltObject::from_int(1).bits()]);
+            let list_bits = MoltObject::from_ptr(list_ptr).bits();
+            let iter_bits = crate::molt_iter(list_bits);
+            for _ in 0..3 {
+                let res = super::molt_iter_send(iter_bits, none);
+                assert!(MoltObject::from_bits(res).is_none());
+                assert_eq!(
+                    take_pending_exception_kind().as_deref(),
+                    Some("AttributeError")
+                );
+                assert_eq!(crate::exception_stack_depth(), depth_before);
+            }
+
+            dec_ref_bits(_py, iter_bits);
+            dec_ref_bits(_py, list_bits);
+            dec_ref_bits(_py, gen_bits);
+        });
+    }
+
+    #[test]
+    fn next_returns_items_then_default_or_stop_iteration() {
+        let _guard = crate::TEST_MUTEX
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let _ = crate::molt_exception_clear();
+        let list_bits = crate::with_gil_entry_nopanic!(_py, {
+            let list_ptr = crate::alloc_list(
+                _py,
+                &[
+                    MoltObject::from_int(7).bits(),
+                    MoltObject::from_int(8).bits(),
+                ],
+            );
+            MoltObject::from_ptr(list_ptr).bits()
+        });
+        let iter_bits = crate::molt_iter(list_bits);
+        let missing = crate::with_gil_entry_nopanic!(_py, { super::missing_bits(_py) });
+        let fallback = MoltObject::from_int(-1).bits();
+
+        // The missing sentinel means no default was passed.
+        assert_eq!(
+            super::molt_next_builtin(iter_bits, missing),
+            MoltObject::from_int(7).bits()
+        );
+        assert_eq!(
+            super::molt_next_builtin(iter_bits, fallback),
+            MoltObject::from_int(8).bits()
+        );
+        assert_eq!(super::molt_next_builtin(iter_bits, fallback), fallback);
+        assert_eq!(take_pending_exception_kind(), None);
+
+        assert!(MoltObject::from_bits(super::molt_next_builtin(iter_bits, missing)).is_none());
+        assert_eq!(
+            take_pending_exception_kind().as_deref(),
+            Some("StopIteration")
+        );
+
+        // Non-iterators are rejected even when a default is supplied.
+        assert!(MoltObject::from_bits(super::molt_next_builtin(list_bits, fallback)).is_none());
+        assert_eq!(take_pending_exception_kind().as_deref(), Some("TypeError"));
+
+        crate::with_gil_entry_nopanic!(_py, {
+            dec_ref_bits(_py, iter_bits);
+            dec_ref_bits(_py, list_bits);
+        });
+    }
+
+    extern "C" fn return_value_poll(_task_bits: u64) -> i64 {
+        crate::with_gil_entry_nopanic!(_py, {
+            let pair = crate::alloc_tuple(
+                _py,
+                &[
+                    MoltObject::from_int(42).bits(),
+                    MoltObject::from_bool(true).bits(),
+                ],
+            );
+            MoltObject::from_ptr(pair).bits() as i64
+        })
+    }
+
+    #[test]
+    fn exhausted_generator_raises_stop_iteration_with_return_value() {
+        let _guard = crate::TEST_MUTEX
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let _ = crate::molt_exception_clear();
+        let gen_bits = crate::molt_generator_new(
+            return_value_poll as extern "C" fn(u64) -> i64 as usize as u64,
+            crate::GEN_CONTROL_SIZE as u64,
+        );
+        let missing = crate::with_gil_entry_nopanic!(_py, { super::missing_bits(_py) });
+        assert!(MoltObject::from_bits(super::molt_next_builtin(gen_bits, missing)).is_none());
+
+        let exc_bits = crate::molt_exception_last();
+        let exc_ptr = MoltObject::from_bits(exc_bits)
+            .as_ptr()
+            .expect("pending exception");
+        crate::with_gil_entry_nopanic!(_py, {
+            unsafe {
+                assert_eq!(
+                    crate::string_obj_to_owned(MoltObject::from_bits(crate::exception_kind_bits(
+                        exc_ptr
+                    )))
+                    .as_deref(),
+                    Some("StopIteration")
+                );
+                // `.value` and `.args[0]` are the returned object, not its str.
+                assert_eq!(
+                    crate::exception_value_bits(exc_ptr),
+                    MoltObject::from_int(42).bits()
+                );
+                let args_ptr = MoltObject::from_bits(crate::exception_args_bits(exc_ptr))
+                    .as_ptr()
+                    .expect("args tuple");
+                assert_eq!(
+                    seq_vec_ref(args_ptr).as_slice(),
+                    &[MoltObject::from_int(42).bits()]
+                );
+                let class_bits = crate::exception_class_bits(exc_ptr);
+                let exception_bits =
+                    crate::builtins::exceptions::exception_type_bits_from_name(_py, "Exception");
+                assert!(crate::builtins::type_ops::issubclass_bits(
+                    class_bits,
+                    exception_bits
+                ));
+            }
+            dec_ref_bits(_py, exc_bits);
+        });
+        let _ = crate::molt_exception_clear();
+        crate::with_gil_entry_nopanic!(_py, {
+            dec_ref_bits(_py, gen_bits);
+        });
+    }
 }
@@ -182,6 +182,10 @@ pub extern "C" fn molt_list_append(list_bits: u64, val_bits: u64) -> u64 {
     })
 }
 
+/// `list.pop([i])`. A `None` index pops the last element. A negative index
+/// counts from the end once (`i += len`); anything still outside `[0, len)`
+/// raises `IndexError`, as does popping from an empty list. There is no
+/// clamping.
 #[unsafe(no_mangle)]
 pub extern "C" fn molt_list_pop(list_bits: u64, index_bits: u64) -> u64 {
     crate::with_gil_entry_nopanic!(_py, {
@@ -356,6 +360,9 @@ pub extern "C" fn molt_list_extend(list_bits: u64, other_bits: u64) -> u64 {
     })
 }
 
+/// `list.insert(i, x)`. Never raises for an out-of-range index: a negative
+/// index counts from the end once (`i += len`), then the result is clamped to
+/// `[0, len]`, so `insert(-100, x)` prepends and `insert(100, x)` appends.
 #[unsafe(no_mangle)]
 pub extern "C" fn molt_list_insert(list_bits: u64, index_bits: u64, val_bits: u64) -> u64 {
     crate::with_gil_entry_nopanic!(_py, {
@@ -1144,6 +1151,10 @@ pub extern "C" fn molt_list_count(list_bits: u64, val_bits: u64) -> u64 {
     })
 }
 
+/// `list.index(x[, start[, stop]])`; missing bounds are passed as the missing
+/// sentinel and default to `0` and `len`. Each bound is normalized like a
+/// slice index: negative values count from the end once, then both are
+/// clamped to `[0, len]`. An empty window (`start >= stop`) finds nothing.
 #[unsafe(no_mangle)]
 pub extern "C" fn molt_list_index_range(
     list_bits: u64,
@@ -1362,3 +1373,81 @@ pub extern "C" fn molt_tuple_index_range(
         MoltObject::none().bits()
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::take_pending_exception_kind;
+
+    fn int_list(values: &[i64]) -> u64 {
+        let elems: Vec<u64> = values
+            .iter()
+            .map(|&v| MoltObject::from_int(v).bits())
+            .collect();
+        crate::with_gil_entry_nopanic!(_py, {
+            MoltObject::from_ptr(alloc_list(_py, &elems)).bits()
+        })
+    }
+
+    fn list_ints(list_bits: u64) -> Vec<i64> {
+        let ptr = obj_from_bits(list_bits).as_ptr().expect("list pointer");
+        unsafe { seq_vec_ref(ptr) }
+            .iter()
+            .map(|&bits| obj_from_bits(bits).as_int().expect("int element"))
+            .collect()
+    }
+
+    fn int_bits(value: i64) -> u64 {
+        MoltObject::from_int(value).bits()
+    }
+
+    #[test]
+    fn insert_clamps_out_of_range_indices() {
+        let _guard = crate::TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        let list = int_list(&[1, 2, 3]);
+        molt_list_insert(list, int_bits(-100), int_bits(0));
+        molt_list_insert(list, int_bits(100), int_bits(9));
+        molt_list_insert(list, int_bits(-1), int_bits(7));
+        assert_eq!(take_pending_exception_kind(), None);
+        assert_eq!(list_ints(list), vec![0, 1, 2, 3, 7, 9]);
+        crate::molt_dec_ref_obj(list);
+    }
+
+    #[test]
+    fn pop_normalizes_negative_indices_and_rejects_out_of_range() {
+        let _guard = crate::TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        let list = int_list(&[1, 2, 3]);
+        let none = MoltObject::none().bits();
+        assert_eq!(molt_list_pop(list, none), int_bits(3));
+        assert_eq!(molt_list_pop(list, int_bits(-2)), int_bits(1));
+        molt_list_pop(list, int_bits(-2));
+        assert_eq!(take_pending_exception_kind().as_deref(), Some("IndexError"));
+        molt_list_pop(list, int_bits(1));
+        assert_eq!(take_pending_exception_kind().as_deref(), Some("IndexError"));
+        assert_eq!(list_ints(list), vec![2]);
+        assert_eq!(molt_list_pop(list, int_bits(-1)), int_bits(2));
+        molt_list_pop(list, int_bits(-1));
+        assert_eq!(take_pending_exception_kind().as_deref(), Some("IndexError"));
+        crate::molt_dec_ref_obj(list);
+    }
+
+    #[test]
+    fn index_respects_start_and_stop_bounds() {
+        let _guard = crate::TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        let list = int_list(&[5, 6, 5, 6]);
+        let missing = crate::with_gil_entry_nopanic!(_py, { missing_bits(_py) });
+        assert_eq!(
+            molt_list_index_range(list, int_bits(6), int_bits(2), missing),
+            int_bits(3)
+        );
+        assert_eq!(
+            molt_list_index_range(list, int_bits(5), int_bits(-2), int_bits(100)),
+            int_bits(2)
+        );
+        molt_list_index_range(list, int_bits(6), int_bits(0), int_bits(1));
+        assert_eq!(take_pending_exception_kind().as_deref(), Some("ValueError"));
+        molt_list_index_range(list, int_bits(5), int_bits(3), int_bits(-3));
+        assert_eq!(take_pending_exception_kind().as_deref(), Some("ValueError"));
+        crate::molt_dec_ref_obj(list);
+    }
+}
@@ -10,6 +10,7 @@ use crate::object::utf8_cache::{
 use crate::*;
 use memchr::memmem;
 use molt_obj_model::MoltObject;
+use num_traits::ToPrimitive;
 use unicode_casefold::{Locale, UnicodeCaseFold, Variant};
 use unicode_ident::{is_xid_continue, is_xid_start};
 use wtf8::{CodePoint, Wtf8};
@@ -3732,39 +3733,32 @@ pub extern "C" fn molt_str_repeat(str_bits: u64, count_bits: u64) -> u64 {
     crate::with_gil_entry_nopanic!(_py, {
         let str_obj = obj_from_bits(str_bits);
         let count_obj = obj_from_bits(count_bits);
-        let Some(ptr) = str_obj.as_ptr() else {
-            return raise_exception::<_>(
-                _py,
-                "TypeError",
-                "can't multiply sequence by non-int of type 'NoneType'",
+        let Some(ptr) = str_obj
+            .as_ptr()
+            .filter(|&ptr| unsafe { object_type_id(ptr) } == TYPE_ID_STRING)
+        else {
+            let msg = format!(
+                "descriptor '__mul__' requires a 'str' object but received a '{}'",
+                type_name(_py, str_obj)
             );
+            return raise_exception::<_>(_py, "TypeError", &msg);
         };
-        unsafe {
-            if object_type_id(ptr) != TYPE_ID_STRING {
-                return raise_exception::<_>(
-                    _py,
-                    "TypeError",
-                    &format!(
-                        "can't multiply sequence by non-int of type '{}'",
-                        type_of_bits(_py, str_bits)
-                    ),
-                );
-            }
-        }
-        let Some(count) = to_i64(count_obj) else {
+        let err = format!(
+            "can't multiply sequence by non-int of type '{}'",
+            type_name(_py, count_obj)
+        );
+        let Some(count) = index_bigint_from_obj(_py, count_bits, &err) else {
+            return MoltObject::none().bits();
+        };
+        let Some(count) = count.to_i64() else {
             return raise_exception::<_>(
                 _py,
-                "TypeError",
-                &format!(
-                    "can't multiply sequence by non-int of type '{}'",
-                    type_of_bits(_py, count_bits)
-                ),
+                "OverflowError",
+                "cannot fit 'int' into an index-sized integer",
             );
         };
-        match repeat_sequence(_py, ptr, count) {
-            Some(bits) => bits,
-            None => raise_exception::<_>(_py, "TypeError", "unsupported operand type(s) for *"),
-        }
+        // The operand is a str, so `None` means the repeat already raised.
+        repeat_sequence(_py, ptr, count).unwrap_or_else(|| MoltObject::none().bits())
     })
 }
 
@@ -3856,3 +3850,58 @@ pub extern "C" fn molt_string_title(hay_bits: u64) -> u64 {
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::take_pending_exception;
+
+    #[test]
+    fn molt_str_repeat_tiles_and_reports_overflow() {
+        let _guard = crate::TEST_MUTEX
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let _ = crate::molt_exception_clear();
+        crate::with_gil_entry_nopanic!(_py, {
+            let ab = MoltObject::from_ptr(alloc_string(_py, b"ab")).bits();
+            let out = molt_str_repeat(ab, MoltObject::from_int(3).bits());
+            assert_eq!(
+                string_obj_to_owned(obj_from_bits(out)).as_deref(),
+                Some("ababab")
+            );
+            dec_ref_bits(_py, out);
+
+            let out = molt_str_repeat(ab, MoltObject::from_int(-2).bits());
+            assert_eq!(string_obj_to_owned(obj_from_bits(out)).as_deref(), Some(""));
+            dec_ref_bits(_py, out);
+
+            let huge = int_bits_from_i128(_py, (i64::MAX / 2 + 1) as i128);
+            let empty = MoltObject::from_ptr(alloc_string(_py, b"")).bits();
+            let out = molt_str_repeat(empty, huge);
+            assert_eq!(out, empty);
+            dec_ref_bits(_py, out);
+
+            let _ = molt_str_repeat(ab, huge);
+            assert_eq!(
+                take_pending_exception().expect("pending exception"),
+                (
+                    "OverflowError".to_string(),
+                    "repeated string is too long".to_string()
+                )
+            );
+
+            let _ = molt_str_repeat(ab, MoltObject::from_float(2.0).bits());
+            assert_eq!(
+                take_pending_exception().expect("pending exception"),
+                (
+                    "TypeError".to_string(),
+                    "can't multiply sequence by non-int of type 'float'".to_string()
+                )
+            );
+
+            dec_ref_bits(_py, huge);
+            dec_ref_bits(_py, empty);
+            dec_ref_bits(_py, ab);
+        });
+    }
+}
@@ -1248,7 +1248,7 @@ pub extern "C" fn molt_object_hash(val: u64) -> u64 {
     crate::with_gil_entry_nopanic!(_py, {
         let obj = obj_from_bits(val);
         let hash = if let Some(ptr) = obj.as_ptr() {
-            hash_pointer(ptr as u64)
+            hash_object_identity(ptr)
         } else {
             hash_pointer(val)
         };
@@ -2902,6 +2902,14 @@ pub extern "C" fn molt_traceback_extract_tb(tb_bits: u64, limit_bits: u64) -> u6
     })
 }
 
+/// Recursion guard for generated Python-level calls.
+///
+/// Every generated call site brackets the callee body with
+/// `molt_recursion_guard_enter` / `molt_recursion_guard_exit`. Returns 1 when
+/// the call may proceed; when the limit is exceeded it raises
+/// `RecursionError("maximum recursion depth exceeded")` and returns 0, in which
+/// case the caller must not call `molt_recursion_guard_exit` and should
+/// propagate the pending exception.
 #[unsafe(no_mangle)]
 pub extern "C" fn molt_recursion_guard_enter() -> i64 {
     crate::with_gil_entry_nopanic!(_py, {
@@ -2913,6 +2921,7 @@ pub extern "C" fn molt_recursion_guard_enter() -> i64 {
     })
 }
 
+/// Pops one level pushed by a successful `molt_recursion_guard_enter`.
 #[unsafe(no_mangle)]
 pub extern "C" fn molt_recursion_guard_exit() {
     crate::with_gil_entry_nopanic!(_py, {
@@ -3128,3 +3137,57 @@ pub extern "C" fn molt_int_from_bytes(
         unsafe { call_callable1(_py, class_bits, int_bits) }
     })
 }
+
+#[cfg(test)]
+mod recursion_guard_tests {
+    use super::*;
+
+    fn ping(depth: &mut usize) -> bool {
+        if molt_recursion_guard_enter() == 0 {
+            return false;
+        }
+        *depth += 1;
+        let ok = pong(depth);
+        molt_recursion_guard_exit();
+        ok
+    }
+
+    fn pong(depth: &mut usize) -> bool {
+        if molt_recursion_guard_enter() == 0 {
+            return false;
+        }
+        *depth += 1;
+        let ok = ping(depth);
+        molt_recursion_guard_exit();
+        ok
+    }
+
+    #[test]
+    fn mutual_recursion_raises_recursion_error_at_limit() {
+        let _guard = crate::TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = crate::molt_exception_clear();
+        let prev_limit = recursion_limit_get();
+        recursion_limit_set(64);
+
+        let mut depth = 0usize;
+        assert!(!ping(&mut depth));
+        assert_eq!(depth, 64);
+
+        assert_eq!(
+            crate::test_support::take_pending_exception(),
+            Some((
+                "RecursionError".to_string(),
+                "maximum recursion depth exceeded".to_string()
+            ))
+        );
+
+        // Every successful enter was paired with an exit, so the full budget
+        // is available again.
+        let mut depth = 0usize;
+        assert!(!ping(&mut depth));
+        assert_eq!(depth, 64);
+        let _ = crate::molt_exception_clear();
+
+        recursion_limit_set(prev_limit);
+    }
+}
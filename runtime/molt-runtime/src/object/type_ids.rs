@@ -55,6 +55,9 @@ pub(crate) const TYPE_ID_NATIVE_HANDLE: u32 = 252;
 /// (CPython-faithful `glob` algorithm, but incremental instead of eager).
 pub(crate) const TYPE_ID_GLOB_ITER: u32 = 253;
 
+/// Shared `AtomicI64` counter (see `object/atomic.rs`).
+pub(crate) const TYPE_ID_ATOMIC: u32 = 254;
+
 pub(crate) const TYPE_TAG_ANY: i64 = 0;
 pub(crate) const TYPE_TAG_INT: i64 = 1;
 pub(crate) const TYPE_TAG_FLOAT: i64 = 2;
@@ -131,7 +134,7 @@ pub(crate) const TYPE_ID_LIST_BOOL: u32 = molt_codegen_abi::TYPE_ID_LIST_BOOL;
 pub(crate) const TYPE_ID_FLOAT: u32 = 249;
 
 pub(crate) const MIN_HEAP_TYPE_ID: u32 = TYPE_ID_STRING;
-pub(crate) const MAX_HEAP_TYPE_ID: u32 = TYPE_ID_GLOB_ITER;
+pub(crate) const MAX_HEAP_TYPE_ID: u32 = TYPE_ID_ATOMIC;
 
 #[inline]
 pub(crate) fn is_valid_heap_type_id(type_id: u32) -> bool {
@@ -149,6 +152,7 @@ mod tests {
         assert!(is_valid_heap_type_id(TYPE_ID_FLOAT));
         assert!(is_valid_heap_type_id(TYPE_ID_LIST_BOOL));
         assert!(is_valid_heap_type_id(TYPE_ID_GLOB_ITER));
+        assert!(is_valid_heap_type_id(TYPE_ID_ATOMIC));
 
         assert!(!is_valid_heap_type_id(0));
         assert!(!is_valid_heap_type_id(TYPE_ID_OBJECT - 1));
@@ -0,0 +1,44 @@
+//! Process-global slots for callbacks a host installs through the C API.
+
+use std::sync::Mutex;
+
+/// A host-installed set of callbacks `F` and the opaque context handed back to
+/// them. Both are published under one lock, so a caller never pairs one
+/// installation's callbacks with another installation's `ctx`.
+pub(crate) struct HostHook<F: Copy> {
+    slot: Mutex<HostHookSlot<F>>,
+}
+
+#[derive(Clone, Copy)]
+struct HostHookSlot<F: Copy> {
+    funcs: F,
+    ctx: *mut u8,
+}
+
+// SAFETY: `ctx` is an opaque host pointer that the runtime never
+// dereferences; it only travels between threads to be handed back to the
+// host's own callbacks.
+unsafe impl<F: Copy + Send> Send for HostHookSlot<F> {}
+
+impl<F: Copy> HostHook<F> {
+    pub(crate) const fn new(funcs: F) -> Self {
+        Self {
+            slot: Mutex::new(HostHookSlot {
+                funcs,
+                ctx: std::ptr::null_mut(),
+            }),
+        }
+    }
+
+    /// Replace the callbacks and their context as one unit.
+    pub(crate) fn set(&self, funcs: F, ctx: *mut u8) {
+        *self.slot.lock().unwrap_or_else(|e| e.into_inner()) = HostHookSlot { funcs, ctx };
+    }
+
+    /// The installed callbacks with the context to pass them. The lock is
+    /// released before returning, so callers invoke the callbacks unlocked.
+    pub(crate) fn get(&self) -> (F, *mut u8) {
+        let slot = *self.slot.lock().unwrap_or_else(|e| e.into_inner());
+        (slot.funcs, slot.ctx)
+    }
+}
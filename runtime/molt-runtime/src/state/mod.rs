@@ -1,6 +1,8 @@
 pub(crate) mod cache;
+pub(crate) mod host_hook;
 pub(crate) mod lifecycle;
 pub(crate) mod metrics;
+pub(crate) mod output;
 #[allow(dead_code)]
 pub(crate) mod recursion;
 pub(crate) mod runtime_state;
@@ -16,11 +18,13 @@ pub(crate) use metrics::{
     profile_hit_bytes, profile_hit_unchecked, sample_peak_rss,
 };
 // The extern "C" profiling entrypoints only exist on non-wasm32 targets.
+pub(crate) use host_hook::HostHook;
 #[cfg(not(target_arch = "wasm32"))]
 pub(crate) use metrics::{
     molt_profile_enabled, molt_profile_handle_resolve, molt_profile_snapshot,
     molt_profile_struct_field_store,
 };
+pub(crate) use output::{runtime_stderr, runtime_stdout};
 pub(crate) use recursion::{
     recursion_guard_enter, recursion_guard_exit, recursion_limit_get, recursion_limit_set,
 };
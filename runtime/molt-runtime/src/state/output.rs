@@ -0,0 +1,151 @@
+//! Host-installable sinks for output the runtime writes on its own behalf:
+//! the raw `print` fallback used before `sys.stdout` exists, the
+//! `print_newline` op, compiler-emitted warnings, uncaught-exception
+//! tracebacks, exceptions ignored in `__del__`, and exceptions escaping a
+//! spawned thread. Writes made through a `sys.stdout`/`sys.stderr` file object
+//! go through the file layer and are not affected, and neither are the
+//! `MOLT_*` debug traces, which always go to the process stderr.
+//!
+//! When no sink is installed, output goes to the process streams as before.
+
+use std::io::Write;
+
+use super::HostHook;
+
+/// `fn(ctx, data, len)`; `data` is only valid for the duration of the call.
+pub type MoltOutputSink = extern "C" fn(*mut u8, *const u8, usize);
+
+/// The `(stdout, stderr)` sinks, sharing one context.
+static OUTPUT_HOOK: HostHook<(Option<MoltOutputSink>, Option<MoltOutputSink>)> =
+    HostHook::new((None, None));
+
+/// Install (or, with null function pointers, remove) the output sinks.
+///
+/// Thread safety: the sinks are process-global and may be invoked from any
+/// thread running Molt code, including concurrently. The sinks and `ctx` are
+/// swapped together, so every write sees either the old hook or the new one,
+/// never a mix; a write already in flight may still finish on the old hook.
+#[unsafe(no_mangle)]
+pub extern "C" fn molt_set_output_hook(
+    stdout_fn: Option<MoltOutputSink>,
+    stderr_fn: Option<MoltOutputSink>,
+    ctx: *mut u8,
+) {
+    OUTPUT_HOOK.set((stdout_fn, stderr_fn), ctx);
+}
+
+#[derive(Clone, Copy)]
+enum Stream {
+    Stdout,
+    Stderr,
+}
+
+/// Runtime output stream that forwards to the host sink when one is
+/// installed, and to the process stream otherwise.
+pub(crate) struct RuntimeOutput {
+    stream: Stream,
+}
+
+pub(crate) fn runtime_stdout() -> RuntimeOutput {
+    RuntimeOutput {
+        stream: Stream::Stdout,
+    }
+}
+
+pub(crate) fn runtime_stderr() -> RuntimeOutput {
+    RuntimeOutput {
+        stream: Stream::Stderr,
+    }
+}
+
+impl RuntimeOutput {
+    /// The installed sink for this stream, with the context to pass it.
+    fn sink(&self) -> Option<(MoltOutputSink, *mut u8)> {
+        let ((stdout, stderr), ctx) = OUTPUT_HOOK.get();
+        let sink = match self.stream {
+            Stream::Stdout => stdout,
+            Stream::Stderr => stderr,
+        };
+        sink.map(|sink| (sink, ctx))
+    }
+
+    /// Write `text` followed by a newline, flushing stdout first for stderr
+    /// writes so merged streams keep their order (the `eprintln!` contract).
+    pub(crate) fn write_line(&mut self, text: &str) {
+        if matches!(self.stream, Stream::Stderr) {
+            let _ = runtime_stdout().flush();
+        }
+        let _ = self.write_all(text.as_bytes());
+        let _ = self.write_all(b"\n");
+        let _ = self.flush();
+    }
+}
+
+impl Write for RuntimeOutput {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if let Some((sink, ctx)) = self.sink() {
+            sink(ctx, buf.as_ptr(), buf.len());
+            return Ok(buf.len());
+        }
+        match self.stream {
+            Stream::Stdout => std::io::stdout().write(buf),
+            Stream::Stderr => std::io::stderr().write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        if self.sink().is_some() {
+            return Ok(());
+        }
+        match self.stream {
+            Stream::Stdout => std::io::stdout().flush(),
+            Stream::Stderr => std::io::stderr().flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    static CAPTURED: Mutex<Vec<(usize, Vec<u8>)>> = Mutex::new(Vec::new());
+
+    extern "C" fn capture_stdout(ctx: *mut u8, data: *const u8, len: usize) {
+        let bytes = unsafe { std::slice::from_raw_parts(data, len) }.to_vec();
+        CAPTURED.lock().unwrap().push((ctx as usize, bytes));
+    }
+
+    extern "C" fn capture_stderr(ctx: *mut u8, data: *const u8, len: usize) {
+        let bytes = unsafe { std::slice::from_raw_parts(data, len) }.to_vec();
+        CAPTURED.lock().unwrap().push((ctx as usize + 1, bytes));
+    }
+
+    #[test]
+    fn output_hook_captures_runtime_streams() {
+        let _guard = crate::TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        CAPTURED.lock().unwrap().clear();
+        molt_set_output_hook(
+            Some(capture_stdout),
+            Some(capture_stderr),
+            40usize as *mut u8,
+        );
+        crate::object::ops_format::molt_print_newline();
+        runtime_stderr().write_line("boom");
+        molt_set_output_hook(None, None, std::ptr::null_mut());
+
+        let captured: Vec<(usize, Vec<u8>)> = std::mem::take(&mut *CAPTURED.lock().unwrap());
+        let stdout: Vec<u8> = captured
+            .iter()
+            .filter(|(ctx, _)| *ctx == 40)
+            .flat_map(|(_, bytes)| bytes.clone())
+            .collect();
+        let stderr: Vec<u8> = captured
+            .iter()
+            .filter(|(ctx, _)| *ctx == 41)
+            .flat_map(|(_, bytes)| bytes.clone())
+            .collect();
+        assert_eq!(stdout, b"\n");
+        assert_eq!(stderr, b"boom\n");
+    }
+}
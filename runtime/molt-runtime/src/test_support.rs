@@ -0,0 +1,30 @@
+//! Helpers shared by the runtime's unit tests.
+
+use crate::{MoltObject, string_obj_to_owned};
+
+/// Take the pending exception and return its `(kind, message)`.
+///
+/// Returns `None` when no exception is pending. Otherwise the exception is
+/// cleared, so consecutive checks each see only the error they provoked.
+pub(crate) fn take_pending_exception() -> Option<(String, String)> {
+    let exc_bits = crate::molt_exception_last();
+    if MoltObject::from_bits(exc_bits).is_none() {
+        return None;
+    }
+    let kind_bits = crate::molt_exception_kind(exc_bits);
+    let msg_bits = crate::builtins::exceptions::molt_exception_message(exc_bits);
+    let kind = string_obj_to_owned(MoltObject::from_bits(kind_bits))
+        .expect("exception kind must be string");
+    let msg = string_obj_to_owned(MoltObject::from_bits(msg_bits))
+        .expect("exception message must be string");
+    crate::molt_dec_ref_obj(msg_bits);
+    crate::molt_dec_ref_obj(kind_bits);
+    crate::molt_dec_ref_obj(exc_bits);
+    let _ = crate::molt_exception_clear();
+    Some((kind, msg))
+}
+
+/// Like [`take_pending_exception`], keeping only the exception kind.
+pub(crate) fn take_pending_exception_kind() -> Option<String> {
+    take_pending_exception().map(|(kind, _)| kind)
+}
@@ -401,8 +401,17 @@ INTRINSIC_SYMBOL_NAMES: dict[str, str] = {
     "molt_chan_try_send": "molt_chan_try_send",
     "molt_chan_try_recv": "molt_chan_try_recv",
     "molt_chan_send_blocking": "molt_chan_send_blocking",
+    "molt_chan_send_timeout": "molt_chan_send_timeout",
     "molt_chan_recv_blocking": "molt_chan_recv_blocking",
     "molt_chan_drop": "molt_chan_drop",
+    "molt_objchan_new": "molt_objchan_new",
+    "molt_objchan_send": "molt_objchan_send",
+    "molt_objchan_recv": "molt_objchan_recv",
+    "molt_atomic_new": "molt_atomic_new",
+    "molt_atomic_load": "molt_atomic_load",
+    "molt_atomic_store": "molt_atomic_store",
+    "molt_atomic_add": "molt_atomic_add",
+    "molt_atomic_cas": "molt_atomic_cas",
     "molt_pending": "molt_pending",
     "molt_spawn": "molt_spawn",
     "molt_async_sleep": "molt_async_sleep",
@@ -1226,6 +1235,7 @@ INTRINSIC_SYMBOL_NAMES: dict[str, str] = {
     "molt_exception_last": "molt_exception_last",
     "molt_exception_pending": "molt_exception_pending",
     "molt_exception_clear": "molt_exception_clear",
+    "molt_exception_group_new": "molt_exception_group_new",
     "molt_gc_collect": "molt_gc_collect",
     "molt_gc_enable": "molt_gc_enable",
     "molt_gc_disable": "molt_gc_disable",
@@ -1257,6 +1267,7 @@ INTRINSIC_SYMBOL_NAMES: dict[str, str] = {
     "molt_asyncgen_hooks_set": "molt_asyncgen_hooks_set",
     "molt_asyncgen_locals": "molt_asyncgen_locals",
     "molt_gen_locals": "molt_gen_locals",
+    "molt_iter_send": "molt_iter_send",
     "molt_ast_parse": "molt_ast_parse",
     "molt_ast_walk": "molt_ast_walk",
     "molt_ast_get_docstring": "molt_ast_get_docstring",
@@ -652,10 +652,7 @@ class Counter:
                     pairs = [(k, iterable[k]) for k in iterable]
                 self._handle = _MOLT_COUNTER_FROM_MAPPING(pairs)
             else:
-                if isinstance(iterable, (list, tuple)):
-                    self._handle = _MOLT_COUNTER_FROM_ITERABLE(iterable)
-                else:
-                    self._handle = _MOLT_COUNTER_FROM_ITERABLE(list(iterable))
+                self._handle = _MOLT_COUNTER_FROM_ITERABLE(iterable)
         else:
             self._handle = _MOLT_COUNTER_NEW()
         if kwargs:
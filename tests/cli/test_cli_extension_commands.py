@@ -633,6 +633,20 @@ def test_public_libmolt_header_declares_iterator_and_dict_view_surface() -> None
         assert declaration in header
 
 
+def test_public_libmolt_header_declares_embedding_hooks() -> None:
+    header = (ROOT / "include" / "molt" / "molt.h").read_text(encoding="utf-8")
+
+    for declaration in [
+        "typedef void (*MoltOutputSink)(void *ctx, const uint8_t *data, size_t len);",
+        "void molt_set_output_hook(MoltOutputSink stdout_fn, MoltOutputSink stderr_fn,",
+        "typedef void (*MoltUncaughtHandler)(void *ctx, MoltHandle exc_bits);",
+        "void molt_set_uncaught_handler(MoltUncaughtHandler handler, void *ctx);",
+    ]:
+        assert declaration in header
+    for symbol in ["molt_set_output_hook", "molt_set_uncaught_handler"]:
+        assert f'_molt_host_abi_symbol("{symbol}")' in header
+
+
 def test_extension_scan_fail_on_missing_returns_error(tmp_path: Path, capsys) -> None:
     project_root = tmp_path / "scanproj"
     project_root.mkdir()
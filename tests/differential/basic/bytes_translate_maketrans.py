@@ -23,10 +23,15 @@ print(b"abc".translate(trans, b"b"))
 print(b"abc".translate(None))
 print(b"abc".translate(None, b"b"))
 print(bytearray(b"abc").translate(trans))
+print(b"abc".translate(memoryview(trans), bytearray(b"c")))
+print(b"abc".translate(bytearray(trans), memoryview(b"a")))
+print(b"".translate(trans, b"abc"))
+print(bytes(range(256)).translate(None, bytes(range(1, 256))))
 
 show_exc("maketrans_len", bytes.maketrans, b"ab", b"c")
 show_exc("maketrans_type", bytes.maketrans, "ab", b"cd")
 show_exc("maketrans_args", bytes.maketrans, b"ab")
 
 show_exc("translate_table_len", b"abc".translate, b"abc")
+show_exc("translate_table_len_long", b"abc".translate, trans + b"x")
 show_exc("translate_delete_type", b"abc".translate, trans, "a")
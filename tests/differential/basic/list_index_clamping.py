@@ -0,0 +1,43 @@
+"""Purpose: list.insert/pop/index index normalization matches CPython.
+
+insert clamps any index into [0, len]; pop normalizes a negative index once and
+raises IndexError when it is still out of range; index treats start/stop like
+slice bounds.
+"""
+
+# --- insert clamps instead of raising
+items = [1, 2, 3]
+items.insert(-100, 0)
+items.insert(100, 9)
+items.insert(-1, 7)
+items.insert(0, -1)
+print(items)
+
+# --- pop: default, negative, and out-of-range
+items = [1, 2, 3]
+print(items.pop(), items.pop(-2), items)
+for idx in (-2, 1, 5):
+    try:
+        items.pop(idx)
+    except IndexError as exc:
+        print("IndexError", exc)
+print(items.pop(-1), items)
+try:
+    items.pop(-1)
+except IndexError as exc:
+    print("IndexError", exc)
+try:
+    [].pop()
+except IndexError as exc:
+    print("IndexError", exc)
+
+# --- index with start/stop bounds
+items = [5, 6, 5, 6]
+print(items.index(6, 2))
+print(items.index(5, -2, 100))
+print(items.index(6, -100, 2))
+for args in ((6, 0, 1), (5, 3, -3), (5, 10)):
+    try:
+        items.index(*args)
+    except ValueError:
+        print("ValueError", args)
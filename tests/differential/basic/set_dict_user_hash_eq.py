@@ -0,0 +1,93 @@
+"""Purpose: sets and dicts honor user __eq__/__hash__ together.
+
+Lookups match a candidate by hash first and then confirm with ``__eq__``, so
+equal-but-distinct instances must dedupe, and a class defining ``__eq__``
+without ``__hash__`` must be unhashable.
+"""
+
+
+class Point:
+    def __init__(self, x, y):
+        self.x = x
+        self.y = y
+
+    def __eq__(self, other):
+        if not isinstance(other, Point):
+            return NotImplemented
+        return (self.x, self.y) == (other.x, other.y)
+
+    def __hash__(self):
+        return hash((self.x, self.y))
+
+    def __repr__(self):
+        return f"Point({self.x}, {self.y})"
+
+
+a = Point(1, 2)
+b = Point(1, 2)
+c = Point(2, 1)
+print(a is b, a == b, hash(a) == hash(b))
+
+points = {a, b, c}
+print(len(points), Point(1, 2) in points, Point(3, 3) in points)
+
+table = {a: "first"}
+table[b] = "second"
+print(len(table), table[Point(1, 2)], table.get(c))
+print(sorted(repr(p) for p in points))
+
+
+class Celsius:
+    """Equal to the int with the same value, and hashes like it."""
+
+    def __init__(self, degrees):
+        self.degrees = degrees
+
+    def __eq__(self, other):
+        if isinstance(other, Celsius):
+            return self.degrees == other.degrees
+        if isinstance(other, int):
+            return self.degrees == other
+        return NotImplemented
+
+    def __hash__(self):
+        return hash(self.degrees)
+
+
+mixed = {Celsius(5), 5, 5.0}
+print(len(mixed))
+print({5: "int"}[Celsius(5)])
+
+
+class EqOnly:
+    def __eq__(self, other):
+        return True
+
+
+print(EqOnly.__hash__ is None)
+try:
+    {EqOnly()}
+except TypeError as exc:
+    print("TypeError", "unhashable type: 'EqOnly'" in str(exc))
+try:
+    {EqOnly(): 1}
+except TypeError as exc:
+    print("TypeError", "unhashable type: 'EqOnly'" in str(exc))
+
+
+class Counting:
+    eq_calls = 0
+
+    def __init__(self, key):
+        self.key = key
+
+    def __eq__(self, other):
+        Counting.eq_calls += 1
+        return isinstance(other, Counting) and self.key == other.key
+
+    def __hash__(self):
+        return 0
+
+
+bucket = {Counting(i) for i in range(4)}
+print(len(bucket), Counting(2) in bucket, Counting.eq_calls > 0)
@@ -0,0 +1,74 @@
+"""Purpose: StopIteration.value carries the returned object, not its str.
+
+Covers next() on exhausted generators, explicit generator .send(), and
+``yield from`` over a hand-written iterator that raises StopIteration(obj).
+"""
+
+
+def returns(value):
+    yield "item"
+    return value
+
+
+def exhaust(value):
+    iterator = returns(value)
+    next(iterator)
+    try:
+        next(iterator)
+    except StopIteration as exc:
+        return exc
+
+
+marker = object()
+for value in [42, 1.5, [1, 2], (3, 4), {"k": "v"}, marker, None]:
+    exc = exhaust(value)
+    print(type(exc.value).__name__, exc.value is value, exc.args == (value,) or exc.args == ())
+
+exc = exhaust(None)
+print("none args", exc.args, exc.value)
+
+gen = returns([9])
+gen.send(None)
+try:
+    gen.send("ignored")
+except StopIteration as exc:
+    print("send", exc.value, type(exc.value).__name__)
+
+
+class Countdown:
+    def __init__(self, n):
+        self.n = n
+
+    def __iter__(self):
+        return self
+
+    def __next__(self):
+        if self.n == 0:
+            raise StopIteration({"finished": True})
+        self.n -= 1
+        return self.n
+
+
+def delegate():
+    result = yield from Countdown(2)
+    return result
+
+
+outer = delegate()
+print("delegated", list(outer))
+outer = delegate()
+for _ in outer:
+    pass
+try:
+    next(outer)
+except StopIteration as exc:
+    print("closed", exc.value)
+
+
+def capture():
+    result = yield from Countdown(1)
+    yield result
+
+
+print("captured", list(capture()))
+print("is exception", issubclass(StopIteration, Exception))
@@ -0,0 +1,60 @@
+"""Purpose: str.expandtabs column math matches CPython.
+
+Columns count code points (not UTF-8 bytes), reset after ``\\n`` and ``\\r``,
+and a tabsize of 0 (or a negative one) drops tabs entirely.
+"""
+
+
+def show(value):
+    print(ascii(value))
+
+
+# --- default tabsize of 8
+show("a\tb".expandtabs())
+show("\t".expandtabs())
+show("12345678\tx".expandtabs())
+show("1234567\tx".expandtabs())
+
+# --- explicit tabsize, positional and keyword
+show("a\tbc\tdef\tg".expandtabs(4))
+show("a\tb".expandtabs(tabsize=3))
+show("a\tb".expandtabs(1))
+
+# --- tabsize 0 and negative remove tabs
+show("a\tb\tc".expandtabs(0))
+show("a\tb\tc".expandtabs(-3))
+
+# --- newline and carriage return reset the column
+show("ab\tc\nd\te".expandtabs(4))
+show("abc\r\tx".expandtabs(4))
+show("ab\r\n\tz".expandtabs(4))
+
+# --- non-ASCII characters advance the column by one each
+show("é\tx".expandtabs(4))
+show("日本\tx".expandtabs(4))
+show("\U0001f600\tx".expandtabs(4))
+
+# --- no tabs and empty input
+show("plain text".expandtabs(4))
+show("".expandtabs(4))
+
+# --- bool and index-protocol tabsizes
+
+
+class Four:
+    def __index__(self):
+        return 4
+
+
+show("a\tb".expandtabs(True))
+show("a\tb".expandtabs(Four()))
+
+try:
+    "a\tb".expandtabs(2.0)
+except TypeError as exc:
+    print("TypeError", exc)
+
+try:
+    "a\tb".expandtabs("4")
+except TypeError as exc:
+    print("TypeError", exc)
@@ -0,0 +1,47 @@
+"""Purpose: bounded deque eviction matches CPython.
+
+Appending to a full deque discards from the opposite end; extend/extendleft
+evict one element per item; maxlen=0 drops everything; construction from a
+longer iterable keeps the trailing items.
+"""
+
+from collections import deque
+
+d = deque(range(6), maxlen=3)
+print(d, d.maxlen)
+
+d.append(10)
+print(d)
+d.appendleft(-1)
+print(d)
+
+d.extend([20, 21])
+print(d)
+d.extendleft([30, 31, 32, 33])
+print(d)
+
+d.rotate(1)
+print(d)
+d.rotate(-2)
+print(d)
+
+print(d.pop(), d.popleft(), d, len(d))
+d.append(40)
+d.append(41)
+print(d, len(d))
+
+empty = deque([1, 2, 3], maxlen=0)
+empty.append(4)
+empty.appendleft(5)
+empty.extend([6, 7])
+print(empty, len(empty), bool(empty))
+
+try:
+    deque(maxlen=-1)
+except ValueError as exc:
+    print("ValueError", exc)
+
+try:
+    deque().pop()
+except IndexError as exc:
+    print("IndexError", exc)
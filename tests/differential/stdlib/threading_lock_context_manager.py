@@ -0,0 +1,47 @@
+"""Purpose: threading.Lock acquire/release and `with lock:` match CPython.
+
+Covers the bool results of non-blocking and timed acquires, context-manager
+release on normal and exceptional exit, and RuntimeError when releasing a lock
+that is not held.
+"""
+
+import threading
+
+lock = threading.Lock()
+print(lock.locked())
+
+with lock:
+    print("inside", lock.locked())
+    print("nested nonblocking", lock.acquire(False))
+    print("nested timed", lock.acquire(timeout=0.01))
+print("after with", lock.locked())
+
+try:
+    with lock:
+        raise ValueError("boom")
+except ValueError as exc:
+    print("ValueError", exc, lock.locked())
+
+print("acquire", lock.acquire())
+lock.release()
+
+try:
+    lock.release()
+except RuntimeError as exc:
+    print("RuntimeError", exc)
+
+results = []
+
+
+def worker():
+    for _ in range(100):
+        with lock:
+            results.append(1)
+
+
+threads = [threading.Thread(target=worker) for _ in range(4)]
+for thread in threads:
+    thread.start()
+for thread in threads:
+    thread.join()
+print(len(results), lock.locked())